@@ -0,0 +1,96 @@
+use argon2::{Argon2, PasswordHash, PasswordHasher, PasswordVerifier, password_hash::SaltString};
+
+/// Which parts of the Results page participants may see while admin mode is locked.
+#[derive(serde::Deserialize, serde::Serialize, PartialEq, Clone, Copy, Debug)]
+#[serde(default)]
+pub(crate) struct ResultSections {
+    pub(crate) intensities: bool,
+    pub(crate) graph: bool,
+    pub(crate) profile: bool,
+    pub(crate) highlights: bool
+}
+
+impl Default for ResultSections {
+    fn default() -> Self {
+        Self { intensities: true, graph: true, profile: true, highlights: true }
+    }
+}
+
+/// Facilitator settings. None of the restrictions apply until a passphrase has been set.
+#[derive(serde::Deserialize, serde::Serialize, PartialEq, Clone, Debug, Default)]
+#[serde(default)]
+pub(crate) struct AdminConfig {
+    /// Argon2 hash of the passphrase in PHC string form, empty when none is set.
+    pub(crate) passphrase_hash: String,
+
+    /// Participants only see their results once a facilitator has released them.
+    pub(crate) hide_until_reviewed: bool,
+
+    /// Fingerprint of the instrument the facilitator locked, if any.
+    pub(crate) locked_instrument: Option<String>,
+
+    pub(crate) visible: ResultSections,
+
+    /// The instrument form participants answer, by name.
+    pub(crate) form: String,
+
+    pub(crate) shuffle_groups: bool,
+    pub(crate) shuffle_words: bool
+}
+
+impl AdminConfig {
+
+    pub(crate) fn is_protected(&self) -> bool {
+
+        return !self.passphrase_hash.is_empty();
+    }
+
+    pub(crate) fn set_passphrase(&mut self, passphrase: &str) -> Result<(), String> {
+
+        if passphrase.chars().count() < 8 {
+            return Err("the passphrase must be at least 8 characters".to_owned());
+        }
+
+        let mut salt = [0u8; 16];
+        getrandom::getrandom(&mut salt).map_err(|e| e.to_string())?;
+        let salt = SaltString::encode_b64(&salt).map_err(|e| e.to_string())?;
+
+        self.passphrase_hash = Argon2::default().hash_password(passphrase.as_bytes(), &salt).map_err(|e| e.to_string())?.to_string();
+        return Ok(());
+    }
+
+    pub(crate) fn verify(&self, passphrase: &str) -> bool {
+
+        return match PasswordHash::new(&self.passphrase_hash) {
+            Ok(hash) => Argon2::default().verify_password(passphrase.as_bytes(), &hash).is_ok(),
+            Err(_) => false
+        };
+    }
+
+    /// Whether an instrument may be used, i.e. no version is locked or it is the locked version.
+    pub(crate) fn allows_instrument(&self, questions: &Vec<Vec<(String, crate::app::Choice, crate::app::Choice)>>) -> bool {
+
+        return match &self.locked_instrument {
+            Some(locked) => *locked == instrument_fingerprint(questions),
+            None => true
+        };
+    }
+}
+
+/// A short stable identifier for an instrument's words and scale keys (FNV-1a over its contents).
+pub(crate) fn instrument_fingerprint(questions: &Vec<Vec<(String, crate::app::Choice, crate::app::Choice)>>) -> String {
+
+    let mut hash: u64 = 0xcbf2_9ce4_8422_2325;
+
+    for group in questions {
+        for (word, most, least) in group {
+
+            for b in word.bytes().chain([*most as u8, *least as u8, 0xff]) {
+                hash ^= b as u64;
+                hash = hash.wrapping_mul(0x0100_0000_01b3);
+            }
+        }
+    }
+
+    return format!("{:016x}", hash);
+}
@@ -0,0 +1,81 @@
+use crate::app::Choice;
+
+/// A filled-in answer sheet read from a plain text file.
+///
+/// Each non-empty line answers one group, in instrument order, as the MOST word then the LEAST word separated by a comma:
+///
+/// ```text
+/// # Lines starting with '#' are ignored. "key: value" lines before the answers are kept as details.
+/// name: Jane Doe
+/// focus: Work
+/// enthusiastic, satisfied
+/// determined, cautious
+/// ```
+#[derive(Clone, Debug, Default)]
+pub(crate) struct AnswerSheet {
+    pub(crate) details: Vec<(String, String)>,
+    pub(crate) responses: Vec<(Choice, Choice)>
+}
+
+impl AnswerSheet {
+
+    pub(crate) fn detail(&self, key: &str) -> Option<&str> {
+
+        return self.details.iter().find(|d| d.0.eq_ignore_ascii_case(key)).map(|d| d.1.as_str());
+    }
+}
+
+pub(crate) fn parse_answers(text: &str, questions: &Vec<Vec<(String, Choice, Choice)>>) -> Result<AnswerSheet, String> {
+
+    let mut sheet = AnswerSheet::default();
+
+    for (n, line) in text.lines().enumerate() {
+
+        let line = line.trim();
+
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+
+        if sheet.responses.is_empty() {
+            if let Some((key, value)) = line.split_once(':') {
+                sheet.details.push((key.trim().to_owned(), value.trim().to_owned()));
+                continue;
+            }
+        }
+
+        let group = sheet.responses.len();
+
+        if group >= questions.len() {
+            return Err(format!("line {}: more answers than the {} groups in the instrument", n + 1, questions.len()));
+        }
+
+        let (most, least) = match line.split_once(',') {
+            Some(pair) => (pair.0.trim(), pair.1.trim()),
+            None => return Err(format!("line {}: expected \"MOST word, LEAST word\"", n + 1))
+        };
+
+        sheet.responses.push(find_answer(questions, group, most, least).map_err(|e| format!("line {}: {}", n + 1, e))?);
+    }
+
+    if sheet.responses.len() != questions.len() {
+        return Err(format!("expected {} answered groups but found {}", questions.len(), sheet.responses.len()));
+    }
+
+    return Ok(sheet);
+}
+
+/// The scale keys of one group's MOST and LEAST words.
+pub(crate) fn find_answer(questions: &Vec<Vec<(String, Choice, Choice)>>, group: usize, most: &str, least: &str) -> Result<(Choice, Choice), String> {
+
+    if most.eq_ignore_ascii_case(least) {
+        return Err(format!("\"{}\" is chosen as both MOST and LEAST", most));
+    }
+
+    let find = |word: &str| questions[group].iter().find(|q| q.0.eq_ignore_ascii_case(word));
+
+    let most = find(most).ok_or(format!("\"{}\" is not in group {}", most, group + 1))?;
+    let least = find(least).ok_or(format!("\"{}\" is not in group {}", least, group + 1))?;
+
+    return Ok((most.1, least.2));
+}
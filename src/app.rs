@@ -1,22 +1,98 @@
 use std::{fmt::Debug, f64::consts::E, ops::RangeInclusive};
 use eframe::epaint::RectShape;
-use egui::{Vec2, FontId, TextStyle, Ui, Context, RichText, Color32, Style, Rect, Shape, Sense, plot::{Plot, Points, PlotPoints, PlotPoint, Line, PlotBounds, GridMark, GridInput, BarChart, Bar}, accesskit::Point, Pos2};
-
+use crate::admin::{AdminConfig, ResultSections, instrument_fingerprint};
+use crate::vault::{Sealed, VaultKey};
+use crate::collab::{Collab, Role};
+use crate::audit::{Column, ResponseEvent, summarize, format_duration};
+use crate::instrument::{Form, Layout, Shuffle, STANDARD_FORM, SCALES, standard_form, load_form, read_form, save_form, scale_name, validate};
+use crate::highlights::{DimensionHighlights, HIGHLIGHT_HEADINGS, default_highlights, load_highlights, save_highlights};
+use crate::library::{ProfileEditor, load_library, save_library, edited_field};
+use crate::reflection::{Reflection, ActionItem, parse_due};
+use crate::stats::{Describe, NORM_MIN_RESULTS, norms, percentile_rank};
+use crate::psychometrics::{ItemRecord, MIN_RESPONDENTS, WEAK_CORRELATION, analyze, chosen_words};
+#[cfg(not(target_arch = "wasm32"))]
+use crate::dataset::{DatasetRecord, MIN_SALT_BYTES, write_dataset, random_salt};
+#[cfg(not(target_arch = "wasm32"))]
+use crate::batch::score_directory;
+#[cfg(not(target_arch = "wasm32"))]
+use crate::paper::write_paper_forms;
+use crate::quality::{QualityFlag, SheetRecord, quality_flags, completion_time};
+use chrono::{DateTime, Utc};
+use zeroize::Zeroize;
+use egui::{Vec2, FontId, TextStyle, Ui, Context, RichText, Color32, Style, Rect, Shape, Sense, plot::{Plot, Points, PlotPoints, PlotPoint, Line, PlotBounds, GridMark, GridInput, BarChart, Bar, Polygon, HLine, LineStyle, Legend, Text}, accesskit::Point, Pos2, Align2};
+
+
+#[derive(serde::Deserialize, serde::Serialize, PartialEq, Clone, Copy, Debug)]
+pub(crate) enum Choice { A = 0, B = 1, C = 2, D = 3, E = 4, NONE = 5}
 
 #[derive(PartialEq, Clone, Copy, Debug)]
-enum Choice { A = 0, B = 1, C = 2, D = 3, E = 4, NONE = 5}
+enum Page { Response = 0, Results = 1, Settings = 2, Admin = 3, Team = 4, History = 5, Editor = 6, Profiles = 7, Statistics = 8}
 
 #[derive(PartialEq, Clone, Copy, Debug)]
-enum Page { Response = 0, Results = 1, Settings = 2}
+pub(crate) enum GraphType { Line = 0, Bar = 1, Radar = 2, Circle = 3 }
+
+/// How much of each completion date an anonymized export keeps.
+#[derive(serde::Deserialize, serde::Serialize, PartialEq, Clone, Copy, Debug)]
+pub(crate) enum Coarsen { Exact, Day, Month, Omit }
+
+/// Settings offered before answering; anything else is typed in.
+pub(crate) const FOCUS_PRESETS: [&str; 5] = ["Work", "Home", "Team", "Leadership", "Social"];
 
 #[derive(PartialEq, Clone, Copy, Debug)]
-enum GraphType { Line = 0, Bar = 1 }
+enum Comparison { NONE, Session(usize), TeamAverage }
 
 #[derive(PartialEq, Clone, Debug)]
-struct Profile {
+pub(crate) struct Profile {
+    pub(crate) name: String,
+    pub(crate) aspects: Vec<(String, String)>,
+    pub(crate) content: String
+}
+
+/// A completed answer sheet kept so later results can be compared against it.
+/// A person's record is every session saved under their name, ordered by date.
+#[derive(serde::Deserialize, serde::Serialize, PartialEq, Clone, Debug, Default)]
+struct Session {
     name: String,
-    aspects: Vec<(String, String)>,
-    content: String
+
+    #[serde(default)]
+    completed: Option<DateTime<Utc>>,
+
+    /// The setting the person kept in mind while answering, such as "Work".
+    #[serde(default)]
+    focus: String,
+
+    /// The instrument form answered and how it was shuffled, enough to show the same sheet again.
+    #[serde(default)]
+    form: String,
+
+    #[serde(default)]
+    shuffle: Option<Shuffle>,
+
+    responses: Vec<(Choice, Choice)>,
+    tally: (i8, i8, i8, i8),
+    intensity: (i8, i8, i8, i8),
+
+    #[serde(default)]
+    started: Option<DateTime<Utc>>,
+
+    #[serde(default)]
+    events: Vec<ResponseEvent>,
+
+    /// Words ticked on the segment descriptor chart, for D, i, S and C.
+    #[serde(default)]
+    descriptors: [Vec<String>; 4],
+
+    #[serde(default)]
+    reflection: Reflection
+}
+
+impl Session {
+
+    /// When the session was finished, or its last answer for sessions saved before dates were kept.
+    fn date(&self) -> Option<DateTime<Utc>> {
+
+        return self.completed.or(self.events.last().map(|e| e.at));
+    }
 }
 
 /// We derive Deserialize/Serialize so we can persist app state on shutdown.
@@ -42,6 +118,43 @@ pub struct TemplateApp {
     #[serde(skip)]
     responses: Vec<(Choice, Choice)>,
 
+    // When the current answer sheet was opened and every click made on it.
+    #[serde(skip)]
+    responseStarted: Option<DateTime<Utc>>,
+
+    #[serde(skip)]
+    responseEvents: Vec<ResponseEvent>,
+
+    // Chosen before the answer sheet is shown.
+    #[serde(skip)]
+    focus: String,
+
+    #[serde(skip)]
+    focusConfirmed: bool,
+
+    #[serde(skip)]
+    responseShuffle: Option<Shuffle>,
+
+    // Alternate instrument forms loaded by a facilitator; the standard form is built in.
+    forms: Vec<Form>,
+
+    formPath: String,
+
+    #[serde(skip)]
+    formStatus: String,
+
+    // The instrument being written on the Instrument Editor page; kept between runs as a draft.
+    editorForm: Form,
+
+    editorPath: String,
+
+    #[serde(skip)]
+    editorStatus: String,
+
+    // Answers given while previewing the draft, which are never scored or saved.
+    #[serde(skip)]
+    editorPreview: Option<Vec<(Choice, Choice)>>,
+
     #[serde(skip)]
     tally: (i8, i8, i8, i8),
 
@@ -52,7 +165,115 @@ pub struct TemplateApp {
     profiles: Vec<Profile>,
 
     #[serde(skip)]
-    currentProfile: Profile
+    currentProfile: Profile,
+
+    profileEditor: ProfileEditor,
+
+    #[serde(skip)]
+    descriptorTicks: [Vec<String>; 4],
+
+    #[serde(skip)]
+    reflection: Reflection,
+
+    // The action plan item being typed: goal, due date and any problem with them.
+    #[serde(skip)]
+    actionDraft: (String, String, String),
+
+    // Descriptions of D, i, S and C, which can be exported to a file, edited or translated, and loaded back.
+    #[serde(skip)]
+    highlights: Vec<DimensionHighlights>,
+
+    highlightsPath: String,
+
+    #[serde(skip)]
+    highlightsStatus: String,
+
+    sessions: Vec<Session>,
+
+    #[serde(skip)]
+    sessionName: String,
+
+    #[serde(skip)]
+    comparison: Comparison,
+
+    #[serde(skip)]
+    historyPerson: String,
+
+    #[serde(skip)]
+    historyFocus: String,
+
+    exportPath: String,
+
+    #[serde(skip)]
+    exportWidth: u32,
+
+    #[serde(skip)]
+    exportStatus: String,
+
+    reportPath: String,
+
+    #[serde(skip)]
+    reportStatus: String,
+
+    admin: AdminConfig,
+
+    #[serde(skip)]
+    adminUnlocked: bool,
+
+    #[serde(skip)]
+    passphraseInput: String,
+
+    #[serde(skip)]
+    adminStatus: String,
+
+    // The answers a facilitator last released to the participant.
+    #[serde(skip)]
+    releasedResponses: Vec<(Choice, Choice)>,
+
+    #[serde(skip)]
+    confirmClear: bool,
+
+    // Anonymized research export: file, how much of each date to keep, an optional salt to link exports, and the outcome.
+    datasetPath: String,
+
+    datasetCoarsen: Coarsen,
+
+    #[serde(skip)]
+    datasetSalt: String,
+
+    #[serde(skip)]
+    datasetStatus: String,
+
+    // Printable answer sheet and facilitator scoring key for the loaded form.
+    paperSheetPath: String,
+
+    paperKeyPath: String,
+
+    // Batch scoring: the folder of answer files, where the summary and reports go, and the outcome.
+    batchInput: String,
+
+    batchOutput: String,
+
+    #[serde(skip)]
+    batchStatus: String,
+
+    // When set, sessions are only persisted inside this encrypted blob.
+    sealedSessions: Option<Sealed>,
+
+    #[serde(skip)]
+    vaultKey: Option<VaultKey>,
+
+    #[serde(skip)]
+    vaultPassphrase: String,
+
+    #[serde(skip)]
+    vaultStatus: String,
+
+    // Participants take turns on one machine; see `new_kiosk`.
+    #[serde(skip)]
+    kiosk: bool,
+
+    collab: Collab
 }
 
 impl Default for TemplateApp {
@@ -63,146 +284,7 @@ impl Default for TemplateApp {
             currentPage: Page::Response,
             currentHighlight: Choice::NONE,
             graphType: GraphType::Line,
-            questions: vec![
-                vec![("enthusiastic".to_string(), Choice::B, Choice::B),
-                    ("daring".to_string(), Choice::A, Choice::A),
-                    ("diplomatic".to_string(), Choice::D, Choice::D),
-                    ("satisfied".to_string(), Choice::C, Choice::C)],
-
-                vec![("cautious".to_string(), Choice::D, Choice::D),
-                    ("determined".to_string(), Choice::A, Choice::A),
-                    ("convincing".to_string(), Choice::B, Choice::B),
-                    ("good-natured".to_string(), Choice::C, Choice::E)],
-                    
-                vec![("friendly".to_string(), Choice::B, Choice::E),
-                    ("accurate".to_string(), Choice::D, Choice::D),
-                    ("outspoken".to_string(), Choice::A, Choice::A),
-                    ("calm".to_string(), Choice::E, Choice::C)],
-                
-                vec![("talkative".to_string(), Choice::B, Choice::B),
-                    ("controlled".to_string(), Choice::D, Choice::D),
-                    ("conventional".to_string(), Choice::C, Choice::C),
-                    ("decisive".to_string(), Choice::A, Choice::A)],
-                    
-                vec![("adventurous".to_string(), Choice::A, Choice::A),
-                    ("insightful".to_string(), Choice::D, Choice::D),
-                    ("outgoing".to_string(), Choice::B, Choice::B),
-                    ("moderate".to_string(), Choice::C, Choice::C)],
-                    
-                vec![("gentle".to_string(), Choice::C, Choice::C),
-                    ("persuasive".to_string(), Choice::B, Choice::E),
-                    ("humble".to_string(), Choice::E, Choice::D),
-                    ("original".to_string(), Choice::NONE, Choice::A)],
-                
-                vec![("expressive".to_string(), Choice::B, Choice::B),
-                    ("conscientious".to_string(), Choice::D, Choice::D),
-                    ("dominant".to_string(), Choice::A, Choice::A),
-                    ("responsive".to_string(), Choice::E, Choice::C)], 
-                    
-                vec![("poised".to_string(), Choice::B, Choice::B),
-                    ("observant".to_string(), Choice::D, Choice::E),
-                    ("modest".to_string(), Choice::C, Choice::C),
-                    ("impatient".to_string(), Choice::A, Choice::A)],
-                    
-                vec![("tactful".to_string(), Choice::D, Choice::D),
-                    ("agreeable".to_string(), Choice::C, Choice::C),
-                    ("magnetic".to_string(), Choice::B, Choice::B),
-                    ("insistent".to_string(), Choice::A, Choice::A)],
-                    
-                vec![("brave".to_string(), Choice::A, Choice::A),
-                    ("inspiring".to_string(), Choice::B, Choice::B),
-                    ("submissive".to_string(), Choice::C, Choice::C),
-                    ("timid".to_string(), Choice::E, Choice::D)],
-                    
-                vec![("reserved".to_string(), Choice::D, Choice::D),
-                    ("obliging".to_string(), Choice::C, Choice::C),
-                    ("strong-willed".to_string(), Choice::A, Choice::A),
-                    ("cheerful".to_string(), Choice::B, Choice::B)],
-                    
-                vec![("stimulating".to_string(), Choice::B, Choice::B),
-                    ("kind".to_string(), Choice::C, Choice::C),
-                    ("perceptive".to_string(), Choice::D, Choice::D),
-                    ("independent".to_string(), Choice::A, Choice::A)],
-                    
-                vec![("competitive".to_string(), Choice::A, Choice::A),
-                    ("considerate".to_string(), Choice::C, Choice::C),
-                    ("joyful".to_string(), Choice::B, Choice::B),
-                    ("private".to_string(), Choice::D, Choice::D)],
-                    
-                vec![("fussy".to_string(), Choice::D, Choice::D),
-                    ("obedient".to_string(), Choice::C, Choice::C),
-                    ("firm".to_string(), Choice::A, Choice::A),
-                    ("playful".to_string(), Choice::B, Choice::B)],
-                    
-                vec![("attractive".to_string(), Choice::B, Choice::B),
-                    ("introspective".to_string(), Choice::D, Choice::E),
-                    ("stubborn".to_string(), Choice::A, Choice::A),
-                    ("predictable".to_string(), Choice::C, Choice::C)],
-                    
-                vec![("logical".to_string(), Choice::D, Choice::D),
-                    ("bold".to_string(), Choice::A, Choice::A),
-                    ("loyal".to_string(), Choice::C, Choice::C),
-                    ("charming".to_string(), Choice::B, Choice::B)],
-                    
-                vec![("sociable".to_string(), Choice::B, Choice::B),
-                    ("patient".to_string(), Choice::C, Choice::C),
-                    ("self-reliant".to_string(), Choice::A, Choice::A),
-                    ("soft-spoken".to_string(), Choice::D, Choice::D)],
-                    
-                vec![("willing".to_string(), Choice::C, Choice::C),
-                    ("eager".to_string(), Choice::A, Choice::E),
-                    ("thorough".to_string(), Choice::D, Choice::D),
-                    ("high-spririted".to_string(), Choice::B, Choice::B)],
-                    
-                vec![("aggressive".to_string(), Choice::A, Choice::A),
-                    ("extroverted".to_string(), Choice::B, Choice::B),
-                    ("amiable".to_string(), Choice::C, Choice::C),
-                    ("fearful".to_string(), Choice::E, Choice::D)],
-                    
-                vec![("confident".to_string(), Choice::B, Choice::B),
-                    ("sympathetic".to_string(), Choice::C, Choice::C),
-                    ("impartial".to_string(), Choice::E, Choice::D),
-                    ("assertive".to_string(), Choice::A, Choice::A)],
-                    
-                vec![("well-disciplined".to_string(), Choice::D, Choice::D),
-                    ("generous".to_string(), Choice::C, Choice::C),
-                    ("animated".to_string(), Choice::B, Choice::B),
-                    ("persistent".to_string(), Choice::A, Choice::A)],
-                    
-                vec![("impulsive".to_string(), Choice::B, Choice::B),
-                    ("introverted".to_string(), Choice::D, Choice::D),
-                    ("forceful".to_string(), Choice::A, Choice::A),
-                    ("easygoing".to_string(), Choice::C, Choice::C)],
-                    
-                vec![("good mixer".to_string(), Choice::B, Choice::B),
-                    ("refined".to_string(), Choice::D, Choice::D),
-                    ("vigorous".to_string(), Choice::A, Choice::A),
-                    ("lenient".to_string(), Choice::C, Choice::C)],
-                    
-                vec![("captivating".to_string(), Choice::B, Choice::B),
-                    ("contented".to_string(), Choice::C, Choice::C),
-                    ("demanding".to_string(), Choice::A, Choice::A),
-                    ("compliant".to_string(), Choice::D, Choice::D)],
-                    
-                vec![("argumentative".to_string(), Choice::A, Choice::A),
-                    ("systematic".to_string(), Choice::D, Choice::D),
-                    ("cooperative".to_string(), Choice::C, Choice::C),
-                    ("light-hearted".to_string(), Choice::B, Choice::B)],
-                    
-                vec![("jovial".to_string(), Choice::B, Choice::B),
-                    ("precise".to_string(), Choice::D, Choice::D),
-                    ("direct".to_string(), Choice::A, Choice::A),
-                    ("even-tempered".to_string(), Choice::C, Choice::C)],
-                    
-                vec![("restless".to_string(), Choice::A, Choice::A),
-                    ("neighborly".to_string(), Choice::C, Choice::C),
-                    ("appealing".to_string(), Choice::B, Choice::B),
-                    ("careful".to_string(), Choice::D, Choice::D)],
-                    
-                vec![("respectful".to_string(), Choice::D, Choice::D),
-                    ("pioneering".to_string(), Choice::A, Choice::A),
-                    ("optimistic".to_string(), Choice::B, Choice::B),
-                    ("helpful".to_string(), Choice::C, Choice::C)]],
+            questions: default_questions(),
 
             responses: vec![(Choice::NONE, Choice::NONE),
                             (Choice::NONE, Choice::NONE),
@@ -233,183 +315,396 @@ impl Default for TemplateApp {
                             (Choice::NONE, Choice::NONE),
                             (Choice::NONE, Choice::NONE)],
 
+            responseStarted: None,
+            responseEvents: vec![],
+            focus: "".to_owned(),
+            focusConfirmed: false,
+            responseShuffle: None,
+
+            forms: vec![],
+            formPath: "form.json".to_owned(),
+            formStatus: "".to_owned(),
+
+            editorForm: Form { name: "".to_owned(), questions: vec![] },
+            editorPath: "form.json".to_owned(),
+            editorStatus: "".to_owned(),
+            editorPreview: None,
+
             tally: (0, 0, 0, 0),
             intensity: (1, 1, 1, 1),
 
-            profiles: vec![Profile {name: "Achiever".to_owned(),
-                                    aspects: vec![("Emotions:".to_owned(), "is industrious and diligent; displays frustration".to_owned()),
-                                                    ("Goal:".to_owned(), "personal accomplishments, sometimes at the expense of the group's goal".to_owned()),
-                                                    ("Judges others by:".to_owned(), "ability to achieve concrete results".to_owned()),
-                                                    ("Influences others by:".to_owned(), "accountability for own work".to_owned()),
-                                                    ("Value to the organization:".to_owned(), "sets and completes key result areas for self".to_owned()),
-                                                    ("Overuses:".to_owned(), "self-reliance; absorption in the task".to_owned()),
-                                                    ("Under pressure:".to_owned(), "becomes frustrated and impatient; becomes more of a\"do-er\" and less of a \"delegator\"".to_owned()),
-                                                    ("Fears".to_owned(), "others with competing or inferior work standards affecting results".to_owned()),
-                                                    ("Would increase effectiveness through:".to_owned(), "less \"either-or\" thinking; cleaner task priorities; consideration of optional approaches; willingness to compromise short-term for long-range benefits".to_owned())],
-                                    content: "".to_owned()},
-                            Profile {name: "Agent".to_owned(),
-                                    aspects: vec![("Emotions:".to_owned(), "accepts affection; rejects aggression".to_owned()),
-                                                    ("Goal:".to_owned(), "group acceptance".to_owned()),
-                                                    ("Judges others by:".to_owned(), "commitment to tolerate and include everyone".to_owned()),
-                                                    ("Influences others by:".to_owned(), "empathy; friendship".to_owned()),
-                                                    ("Value to the organization:".to_owned(), "supports, harmonizes, empathizes; focuses on service".to_owned()),
-                                                    ("Overuses:".to_owned(), "kindness".to_owned()),
-                                                    ("Under pressure:".to_owned(), "becomes persuasive, using information or key friendships if necessary".to_owned()),
-                                                    ("Fears".to_owned(), "dissent; conflict".to_owned()),
-                                                    ("Would increase effectiveness through:".to_owned(), "strength in the realization of who they are and what they can do; firmness and self-assertion; ability to say \"no\" when appropriate".to_owned())],
-                                    content: "".to_owned()},
-                            Profile {name: "Appraiser".to_owned(),
-                                    aspects: vec![("Emotions:".to_owned(), "is driven to look good".to_owned()),
-                                                    ("Goal:".to_owned(), "\"victory\" with flair".to_owned()),
-                                                    ("Judges others by:".to_owned(), "ability to initiate activities".to_owned()),
-                                                    ("Influences others by:".to_owned(), "competitive recognition".to_owned()),
-                                                    ("Value to the organization:".to_owned(), "accomplishes goals with the team".to_owned()),
-                                                    ("Overuses:".to_owned(), "authority; ingenuity".to_owned()),
-                                                    ("Under pressure:".to_owned(), "becomes restless, critical, impatient".to_owned()),
-                                                    ("Fears".to_owned(), "\"loss\" or \"failure\"; others' disapproval".to_owned()),
-                                                    ("Would increase effectiveness through:".to_owned(), "individual follow-through; empathy when showing disapproval; steadier pace".to_owned())],
-                                    content: "".to_owned()},
-                            Profile {name: "Counselor".to_owned(),
-                                    aspects: vec![("Emotions:".to_owned(), "is approachable; shows affection and understanding".to_owned()),
-                                                    ("Goal:".to_owned(), "friendship; happiness".to_owned()),
-                                                    ("Judges others by:".to_owned(), "positive acceptance or others; ability to look for the good in people".to_owned()),
-                                                    ("Influences others by:".to_owned(), "personal relationships; \"open door\" policy".to_owned()),
-                                                    ("Value to the organization:".to_owned(), "remains stable and predictable; develops a wide range of friendships; listens to others' feelings".to_owned()),
-                                                    ("Overuses:".to_owned(), "indirect approach; tolerance".to_owned()),
-                                                    ("Under pressure:".to_owned(), "becomes overly flexible and intimate; is too trusting without differentiating among people".to_owned()),
-                                                    ("Fears".to_owned(), "pressuring people; being accused of causing harm".to_owned()),
-                                                    ("Would increase effectiveness through:".to_owned(), "attention to realistic deadlines; initiative to complete the task".to_owned())],
-                                    content: "".to_owned()},
-                            Profile {name: "Creative".to_owned(),
-                                    aspects: vec![("Emotions:".to_owned(), "accepts aggression; restrains expression".to_owned()),
-                                                    ("Goal:".to_owned(), "dominance; unique accomplishments".to_owned()),
-                                                    ("Judges others by:".to_owned(), "personal standards; progressive ideas for accomplishing tasks".to_owned()),
-                                                    ("Influences others by:".to_owned(), "ability to pace development of systems and innovative approaches".to_owned()),
-                                                    ("Value to the organization:".to_owned(), "initiates or designs change".to_owned()),
-                                                    ("Overuses:".to_owned(), "bluntness; critical or condescending attitude".to_owned()),
-                                                    ("Under pressure:".to_owned(), "becomes bored with routine work; sulks when restrained; acts independently".to_owned()),
-                                                    ("Fears".to_owned(), "lack of influence; failure to achieve their standards".to_owned()),
-                                                    ("Would increase effectiveness through:".to_owned(), "warmth; tactful communication; effective team cooperation; recognition of existing sanctions".to_owned())],
-                                    content: "".to_owned()},
-                            Profile {name: "Developer".to_owned(),
-                                    aspects: vec![("Emotions:".to_owned(), "is concerned with meeting personal needs".to_owned()),
-                                                    ("Goal:".to_owned(), "new opportunities".to_owned()),
-                                                    ("Judges others by:".to_owned(), "ability to meet the Developer's standards".to_owned()),
-                                                    ("Influences others by:".to_owned(), "finding solutions to problems; projecting a personal sense of power".to_owned()),
-                                                    ("Value to the organization:".to_owned(), "avoids \"passing the buck\"; seeks new or innovative problem-solving methods".to_owned()),
-                                                    ("Overuses:".to_owned(), "control over people and situations to accomplish his or her own results".to_owned()),
-                                                    ("Under pressure:".to_owned(), "works alone to complete tasks; is belligerant if individualism is threatened or challenging opportunities disappear".to_owned()),
-                                                    ("Fears".to_owned(), "boredom; loss of control".to_owned()),
-                                                    ("Would increase effectiveness through:".to_owned(), "patience, empathy; participation and collaboration with others; follow-through and attention to quality control".to_owned())],
-                                    content: "".to_owned()},
-                            Profile {name: "Inspirational".to_owned(),
-                                    aspects: vec![("Emotions:".to_owned(), "accepts aggression; downplays need for affection".to_owned()),
-                                                    ("Goal:".to_owned(), "control of their environment or audience".to_owned()),
-                                                    ("Judges others by:".to_owned(), "projection of personal strength, character, and social power".to_owned()),
-                                                    ("Influences others by:".to_owned(), "charm, direction, intimidation; use of rewards".to_owned()),
-                                                    ("Value to the organization:".to_owned(), "acts as a \"people mover\"; initiates, demands, compliments, disciplines".to_owned()),
-                                                    ("Overuses:".to_owned(), "attitude that \"the ends justify the means\"".to_owned()),
-                                                    ("Under pressure:".to_owned(), "becomes manipulative, quarrelsome or belligerant".to_owned()),
-                                                    ("Fears".to_owned(), "weak behavior; loss of social status".to_owned()),
-                                                    ("Would increase effectiveness through:".to_owned(), "genuine sensitivity; willingness to help others to succeed in their own personal development".to_owned())],
-                                    content: "".to_owned()},
-                            Profile {name: "Investigator".to_owned(),
-                                    aspects: vec![("Emotions:".to_owned(), "is dispassionate; demonstrates self-discipline".to_owned()),
-                                                    ("Goal:".to_owned(), "power through formal roles and positions of authority".to_owned()),
-                                                    ("Judges others by:".to_owned(), "use of factual information".to_owned()),
-                                                    ("Influences others by:".to_owned(), "determination, tenacity".to_owned()),
-                                                    ("Value to the organization:".to_owned(), "offers comprehensive follow-through; works determinedly on tasks individuall or in a small group".to_owned()),
-                                                    ("Overuses:".to_owned(), "bluntness; suspicion of others".to_owned()),
-                                                    ("Under pressure:".to_owned(), "tends to internalize conflict; holds on to grudges".to_owned()),
-                                                    ("Fears".to_owned(), "involvement with the masses; responsibility to sell abstract ideas".to_owned()),
-                                                    ("Would increase effectiveness through:".to_owned(), "flexibility; acceptance or others; personal involvement with others".to_owned())],
-                                    content: "".to_owned()},
-                            Profile {name: "Objective Thinker".to_owned(),
-                                    aspects: vec![("Emotions:".to_owned(), "rejects interpersonal aggression".to_owned()),
-                                                    ("Goal:".to_owned(), "correctness".to_owned()),
-                                                    ("Judges others by:".to_owned(), "ability to think logically".to_owned()),
-                                                    ("Influences others by:".to_owned(), "use of facts, data, and logical arguments".to_owned()),
-                                                    ("Value to the organization:".to_owned(), "defines and clarifies; obtains, evaluates, and tests information".to_owned()),
-                                                    ("Overuses:".to_owned(), "analysis".to_owned()),
-                                                    ("Under pressure:".to_owned(), "becomes worrisome".to_owned()),
-                                                    ("Fears".to_owned(), "irrational acts; ridicule".to_owned()),
-                                                    ("Would increase effectiveness through:".to_owned(), "self-disclosure; public discussion of their insights and opinions".to_owned())],
-                                    content: "".to_owned()},
-                            Profile {name: "Perfectionist".to_owned(),
-                                    aspects: vec![("Emotions:".to_owned(), "displays competence; is restrained and cautious".to_owned()),
-                                                    ("Goal:".to_owned(), "stability; predictble accomplishments".to_owned()),
-                                                    ("Judges others by:".to_owned(), "precise standards".to_owned()),
-                                                    ("Influences others by:".to_owned(), "attention to detail; accuracy".to_owned()),
-                                                    ("Value to the organization:".to_owned(), "is conscientious; maintains standards; controls quality".to_owned()),
-                                                    ("Overuses:".to_owned(), "procedures and \"fail-safe\" controls; overdependence on people, products, and processes that have worked in past".to_owned()),
-                                                    ("Under pressure:".to_owned(), "becomes tactful and diplomatic".to_owned()),
-                                                    ("Fears".to_owned(), "antogonism".to_owned()),
-                                                    ("Would increase effectiveness through:".to_owned(), "role flexibility; independence and interdependence; belief in self-worth".to_owned())],
-                                    content: "".to_owned()},
-                            Profile {name: "Persuader".to_owned(),
-                                    aspects: vec![("Emotions:".to_owned(), "trusts others; is enthusiastic".to_owned()),
-                                                    ("Goal:".to_owned(), "authority and prestige; status symbols".to_owned()),
-                                                    ("Judges others by:".to_owned(), "ability to express themselves; flexibility".to_owned()),
-                                                    ("Influences others by:".to_owned(), "friendly, open manner; verbal skills".to_owned()),
-                                                    ("Value to the organization:".to_owned(), "sells and closes; delegates responsibility; is poised and confident".to_owned()),
-                                                    ("Overuses:".to_owned(), "enthusiasm; selling ability; optimism".to_owned()),
-                                                    ("Under pressure:".to_owned(), "becomes indecisive and is easily persuaded; becomes organized in order to look good".to_owned()),
-                                                    ("Fears".to_owned(), "fixed environment; complex relationships".to_owned()),
-                                                    ("Would increase effectiveness through:".to_owned(), "challenging assignments; attention to task-oriented service and key details; objective data analysis".to_owned())],
-                                    content: "".to_owned()},
-                            Profile {name: "Practitioner".to_owned(),
-                                    aspects: vec![("Emotions:".to_owned(), "wants to keep up with others in effort and technical performance".to_owned()),
-                                                    ("Goal:".to_owned(), "personal growth".to_owned()),
-                                                    ("Judges others by:".to_owned(), "self-discipline; position and promotions".to_owned()),
-                                                    ("Influences others by:".to_owned(), "confidence in their ability to master new skills; development of \"proper\" procedures and actions".to_owned()),
-                                                    ("Value to the organization:".to_owned(), "is skilled in technical and people problem-solving; displays proficiency and specialization".to_owned()),
-                                                    ("Overuses:".to_owned(), "overattention to personal objectives; unrealistic expectations of others".to_owned()),
-                                                    ("Under pressure:".to_owned(), "becomes restrained; is sensitive to criticism".to_owned()),
-                                                    ("Fears".to_owned(), "being too predictable; no recognition as an \"expert\"".to_owned()),
-                                                    ("Would increase effectiveness through:".to_owned(), "genuine collaboration for common benefit; delegation of key tasks to appropriate individuals".to_owned())],
-                                    content: "".to_owned()},
-                            Profile {name: "Promoter".to_owned(),
-                                    aspects: vec![("Emotions:".to_owned(), "is willing to accept others".to_owned()),
-                                                    ("Goal:".to_owned(), "approval, popularity".to_owned()),
-                                                    ("Judges others by:".to_owned(), "verbal skills".to_owned()),
-                                                    ("Influences others by:".to_owned(), "praise, opportunities, favors".to_owned()),
-                                                    ("Value to the organization:".to_owned(), "relieves tension; promotes projects and people, including him or herself".to_owned()),
-                                                    ("Overuses:".to_owned(), "praise, optimism".to_owned()),
-                                                    ("Under pressure:".to_owned(), "becomes careless and sentimental; is disorganized".to_owned()),
-                                                    ("Fears".to_owned(), "loss of social acceptance and self-worth".to_owned()),
-                                                    ("Would increase effectiveness through:".to_owned(), "control of time; objectivity; sense of urgency; emotional control; follow-through on promises and tasks".to_owned())],
-                                    content: "".to_owned()},
-                            Profile {name: "Result-Oriented".to_owned(),
-                                    aspects: vec![("Emotions:".to_owned(), "verbalizes ego strength; displays rugged individualism".to_owned()),
-                                                    ("Goal:".to_owned(), "dominance and independence".to_owned()),
-                                                    ("Judges others by:".to_owned(), "ability to accomplish tasks quickly".to_owned()),
-                                                    ("Influences others by:".to_owned(), "force of character; diligence".to_owned()),
-                                                    ("Value to the organization:".to_owned(), "persistence; doggedness".to_owned()),
-                                                    ("Overuses:".to_owned(), "impatience; \"win-lose\" competition".to_owned()),
-                                                    ("Under pressure:".to_owned(), "becomes critical and fault-finding; resists participating with a team; may overstep boundaries".to_owned()),
-                                                    ("Fears".to_owned(), "others with take advantage of them; slowness, especially in task activities; being a pushover".to_owned()),
-                                                    ("Would increase effectiveness through:".to_owned(), "explanation of their reasoning and consideration of other views and ideas about goals and solutions to problems; genuine concern for other; patience and humility".to_owned())],
-                                    content: "".to_owned()},
-                            Profile {name: "Specialist".to_owned(),
-                                    aspects: vec![("Emotions:".to_owned(), "is calculatingly moderate; accommodates others".to_owned()),
-                                                    ("Goal:".to_owned(), "maintenance of the status quo; controlled environment".to_owned()),
-                                                    ("Judges others by:".to_owned(), "friendship standards; competence".to_owned()),
-                                                    ("Influences others by:".to_owned(), "consistent performance; accommodating others".to_owned()),
-                                                    ("Value to the organization:".to_owned(), "plans short term; is predictable, consistent; maintains steady pace".to_owned()),
-                                                    ("Overuses:".to_owned(), "modesty; low risk-taking; passive resistance to innovation".to_owned()),
-                                                    ("Under pressure:".to_owned(), "becomes adaptable to those in authority and think with the group".to_owned()),
-                                                    ("Fears".to_owned(), "change, disorganization".to_owned()),
-                                                    ("Would increase effectiveness through:".to_owned(), "public discussion of their ideas; self-confidence based on feedback; shortcut methods".to_owned())],
-                                    content: "".to_owned()},
-                            Profile {name: "Invalid".to_owned(),
-                                    aspects: vec![],
-                                    content: "".to_owned()}],
-
-            currentProfile: Profile { name: "".to_owned(), aspects: vec![], content: "".to_owned() }
+            profiles: default_profiles(),
+
+            currentProfile: Profile { name: "".to_owned(), aspects: vec![], content: "".to_owned() },
+            profileEditor: ProfileEditor::default(),
+            descriptorTicks: Default::default(),
+            reflection: Reflection::default(),
+            actionDraft: ("".to_owned(), "".to_owned(), "".to_owned()),
+
+            highlights: default_highlights(),
+            highlightsPath: "highlights.json".to_owned(),
+            highlightsStatus: "".to_owned(),
+
+            sessions: vec![],
+            sessionName: "".to_owned(),
+            comparison: Comparison::NONE,
+            historyPerson: "".to_owned(),
+            historyFocus: "".to_owned(),
+
+            exportPath: "graph.png".to_owned(),
+            exportWidth: 900,
+            exportStatus: "".to_owned(),
+
+            reportPath: "report.html".to_owned(),
+            reportStatus: "".to_owned(),
+
+            admin: AdminConfig::default(),
+            adminUnlocked: false,
+            passphraseInput: "".to_owned(),
+            adminStatus: "".to_owned(),
+            releasedResponses: vec![],
+            confirmClear: false,
+
+            datasetPath: "dataset.csv".to_owned(),
+            datasetCoarsen: Coarsen::Month,
+            datasetSalt: "".to_owned(),
+            datasetStatus: "".to_owned(),
+            paperSheetPath: "answer_sheet.html".to_owned(),
+            paperKeyPath: "scoring_key.html".to_owned(),
+            batchInput: "answers".to_owned(),
+            batchOutput: "batch".to_owned(),
+            batchStatus: "".to_owned(),
+
+            sealedSessions: None,
+            vaultKey: None,
+            vaultPassphrase: "".to_owned(),
+            vaultStatus: "".to_owned(),
+
+            kiosk: false,
+
+            collab: Collab::default()
         }
     }
 }
 
+/// The 28 groups of four words, each with the scale it scores for MOST and for LEAST.
+pub(crate) fn default_questions() -> Vec<Vec<(String, Choice, Choice)>> {
+
+    return vec![
+        vec![("enthusiastic".to_string(), Choice::B, Choice::B),
+            ("daring".to_string(), Choice::A, Choice::A),
+            ("diplomatic".to_string(), Choice::D, Choice::D),
+            ("satisfied".to_string(), Choice::C, Choice::C)],
+
+        vec![("cautious".to_string(), Choice::D, Choice::D),
+            ("determined".to_string(), Choice::A, Choice::A),
+            ("convincing".to_string(), Choice::B, Choice::B),
+            ("good-natured".to_string(), Choice::C, Choice::E)],
+            
+        vec![("friendly".to_string(), Choice::B, Choice::E),
+            ("accurate".to_string(), Choice::D, Choice::D),
+            ("outspoken".to_string(), Choice::A, Choice::A),
+            ("calm".to_string(), Choice::E, Choice::C)],
+        
+        vec![("talkative".to_string(), Choice::B, Choice::B),
+            ("controlled".to_string(), Choice::D, Choice::D),
+            ("conventional".to_string(), Choice::C, Choice::C),
+            ("decisive".to_string(), Choice::A, Choice::A)],
+            
+        vec![("adventurous".to_string(), Choice::A, Choice::A),
+            ("insightful".to_string(), Choice::D, Choice::D),
+            ("outgoing".to_string(), Choice::B, Choice::B),
+            ("moderate".to_string(), Choice::C, Choice::C)],
+            
+        vec![("gentle".to_string(), Choice::C, Choice::C),
+            ("persuasive".to_string(), Choice::B, Choice::E),
+            ("humble".to_string(), Choice::E, Choice::D),
+            ("original".to_string(), Choice::NONE, Choice::A)],
+        
+        vec![("expressive".to_string(), Choice::B, Choice::B),
+            ("conscientious".to_string(), Choice::D, Choice::D),
+            ("dominant".to_string(), Choice::A, Choice::A),
+            ("responsive".to_string(), Choice::E, Choice::C)], 
+            
+        vec![("poised".to_string(), Choice::B, Choice::B),
+            ("observant".to_string(), Choice::D, Choice::E),
+            ("modest".to_string(), Choice::C, Choice::C),
+            ("impatient".to_string(), Choice::A, Choice::A)],
+            
+        vec![("tactful".to_string(), Choice::D, Choice::D),
+            ("agreeable".to_string(), Choice::C, Choice::C),
+            ("magnetic".to_string(), Choice::B, Choice::B),
+            ("insistent".to_string(), Choice::A, Choice::A)],
+            
+        vec![("brave".to_string(), Choice::A, Choice::A),
+            ("inspiring".to_string(), Choice::B, Choice::B),
+            ("submissive".to_string(), Choice::C, Choice::C),
+            ("timid".to_string(), Choice::E, Choice::D)],
+            
+        vec![("reserved".to_string(), Choice::D, Choice::D),
+            ("obliging".to_string(), Choice::C, Choice::C),
+            ("strong-willed".to_string(), Choice::A, Choice::A),
+            ("cheerful".to_string(), Choice::B, Choice::B)],
+            
+        vec![("stimulating".to_string(), Choice::B, Choice::B),
+            ("kind".to_string(), Choice::C, Choice::C),
+            ("perceptive".to_string(), Choice::D, Choice::D),
+            ("independent".to_string(), Choice::A, Choice::A)],
+            
+        vec![("competitive".to_string(), Choice::A, Choice::A),
+            ("considerate".to_string(), Choice::C, Choice::C),
+            ("joyful".to_string(), Choice::B, Choice::B),
+            ("private".to_string(), Choice::D, Choice::D)],
+            
+        vec![("fussy".to_string(), Choice::D, Choice::D),
+            ("obedient".to_string(), Choice::C, Choice::C),
+            ("firm".to_string(), Choice::A, Choice::A),
+            ("playful".to_string(), Choice::B, Choice::B)],
+            
+        vec![("attractive".to_string(), Choice::B, Choice::B),
+            ("introspective".to_string(), Choice::D, Choice::E),
+            ("stubborn".to_string(), Choice::A, Choice::A),
+            ("predictable".to_string(), Choice::C, Choice::C)],
+            
+        vec![("logical".to_string(), Choice::D, Choice::D),
+            ("bold".to_string(), Choice::A, Choice::A),
+            ("loyal".to_string(), Choice::C, Choice::C),
+            ("charming".to_string(), Choice::B, Choice::B)],
+            
+        vec![("sociable".to_string(), Choice::B, Choice::B),
+            ("patient".to_string(), Choice::C, Choice::C),
+            ("self-reliant".to_string(), Choice::A, Choice::A),
+            ("soft-spoken".to_string(), Choice::D, Choice::D)],
+            
+        vec![("willing".to_string(), Choice::C, Choice::C),
+            ("eager".to_string(), Choice::A, Choice::E),
+            ("thorough".to_string(), Choice::D, Choice::D),
+            ("high-spririted".to_string(), Choice::B, Choice::B)],
+            
+        vec![("aggressive".to_string(), Choice::A, Choice::A),
+            ("extroverted".to_string(), Choice::B, Choice::B),
+            ("amiable".to_string(), Choice::C, Choice::C),
+            ("fearful".to_string(), Choice::E, Choice::D)],
+            
+        vec![("confident".to_string(), Choice::B, Choice::B),
+            ("sympathetic".to_string(), Choice::C, Choice::C),
+            ("impartial".to_string(), Choice::E, Choice::D),
+            ("assertive".to_string(), Choice::A, Choice::A)],
+            
+        vec![("well-disciplined".to_string(), Choice::D, Choice::D),
+            ("generous".to_string(), Choice::C, Choice::C),
+            ("animated".to_string(), Choice::B, Choice::B),
+            ("persistent".to_string(), Choice::A, Choice::A)],
+            
+        vec![("impulsive".to_string(), Choice::B, Choice::B),
+            ("introverted".to_string(), Choice::D, Choice::D),
+            ("forceful".to_string(), Choice::A, Choice::A),
+            ("easygoing".to_string(), Choice::C, Choice::C)],
+            
+        vec![("good mixer".to_string(), Choice::B, Choice::B),
+            ("refined".to_string(), Choice::D, Choice::D),
+            ("vigorous".to_string(), Choice::A, Choice::A),
+            ("lenient".to_string(), Choice::C, Choice::C)],
+            
+        vec![("captivating".to_string(), Choice::B, Choice::B),
+            ("contented".to_string(), Choice::C, Choice::C),
+            ("demanding".to_string(), Choice::A, Choice::A),
+            ("compliant".to_string(), Choice::D, Choice::D)],
+            
+        vec![("argumentative".to_string(), Choice::A, Choice::A),
+            ("systematic".to_string(), Choice::D, Choice::D),
+            ("cooperative".to_string(), Choice::C, Choice::C),
+            ("light-hearted".to_string(), Choice::B, Choice::B)],
+            
+        vec![("jovial".to_string(), Choice::B, Choice::B),
+            ("precise".to_string(), Choice::D, Choice::D),
+            ("direct".to_string(), Choice::A, Choice::A),
+            ("even-tempered".to_string(), Choice::C, Choice::C)],
+            
+        vec![("restless".to_string(), Choice::A, Choice::A),
+            ("neighborly".to_string(), Choice::C, Choice::C),
+            ("appealing".to_string(), Choice::B, Choice::B),
+            ("careful".to_string(), Choice::D, Choice::D)],
+            
+        vec![("respectful".to_string(), Choice::D, Choice::D),
+            ("pioneering".to_string(), Choice::A, Choice::A),
+            ("optimistic".to_string(), Choice::B, Choice::B),
+            ("helpful".to_string(), Choice::C, Choice::C)]];
+}
+
+/// The classical patterns in the order `profilePatternLookup` indexes them, ending with "Invalid".
+pub(crate) fn default_profiles() -> Vec<Profile> {
+
+    return vec![Profile {name: "Achiever".to_owned(),
+                         aspects: vec![("Emotions:".to_owned(), "is industrious and diligent; displays frustration".to_owned()),
+                                         ("Goal:".to_owned(), "personal accomplishments, sometimes at the expense of the group's goal".to_owned()),
+                                         ("Judges others by:".to_owned(), "ability to achieve concrete results".to_owned()),
+                                         ("Influences others by:".to_owned(), "accountability for own work".to_owned()),
+                                         ("Value to the organization:".to_owned(), "sets and completes key result areas for self".to_owned()),
+                                         ("Overuses:".to_owned(), "self-reliance; absorption in the task".to_owned()),
+                                         ("Under pressure:".to_owned(), "becomes frustrated and impatient; becomes more of a\"do-er\" and less of a \"delegator\"".to_owned()),
+                                         ("Fears".to_owned(), "others with competing or inferior work standards affecting results".to_owned()),
+                                         ("Would increase effectiveness through:".to_owned(), "less \"either-or\" thinking; cleaner task priorities; consideration of optional approaches; willingness to compromise short-term for long-range benefits".to_owned())],
+                         content: "".to_owned()},
+                 Profile {name: "Agent".to_owned(),
+                         aspects: vec![("Emotions:".to_owned(), "accepts affection; rejects aggression".to_owned()),
+                                         ("Goal:".to_owned(), "group acceptance".to_owned()),
+                                         ("Judges others by:".to_owned(), "commitment to tolerate and include everyone".to_owned()),
+                                         ("Influences others by:".to_owned(), "empathy; friendship".to_owned()),
+                                         ("Value to the organization:".to_owned(), "supports, harmonizes, empathizes; focuses on service".to_owned()),
+                                         ("Overuses:".to_owned(), "kindness".to_owned()),
+                                         ("Under pressure:".to_owned(), "becomes persuasive, using information or key friendships if necessary".to_owned()),
+                                         ("Fears".to_owned(), "dissent; conflict".to_owned()),
+                                         ("Would increase effectiveness through:".to_owned(), "strength in the realization of who they are and what they can do; firmness and self-assertion; ability to say \"no\" when appropriate".to_owned())],
+                         content: "".to_owned()},
+                 Profile {name: "Appraiser".to_owned(),
+                         aspects: vec![("Emotions:".to_owned(), "is driven to look good".to_owned()),
+                                         ("Goal:".to_owned(), "\"victory\" with flair".to_owned()),
+                                         ("Judges others by:".to_owned(), "ability to initiate activities".to_owned()),
+                                         ("Influences others by:".to_owned(), "competitive recognition".to_owned()),
+                                         ("Value to the organization:".to_owned(), "accomplishes goals with the team".to_owned()),
+                                         ("Overuses:".to_owned(), "authority; ingenuity".to_owned()),
+                                         ("Under pressure:".to_owned(), "becomes restless, critical, impatient".to_owned()),
+                                         ("Fears".to_owned(), "\"loss\" or \"failure\"; others' disapproval".to_owned()),
+                                         ("Would increase effectiveness through:".to_owned(), "individual follow-through; empathy when showing disapproval; steadier pace".to_owned())],
+                         content: "".to_owned()},
+                 Profile {name: "Counselor".to_owned(),
+                         aspects: vec![("Emotions:".to_owned(), "is approachable; shows affection and understanding".to_owned()),
+                                         ("Goal:".to_owned(), "friendship; happiness".to_owned()),
+                                         ("Judges others by:".to_owned(), "positive acceptance or others; ability to look for the good in people".to_owned()),
+                                         ("Influences others by:".to_owned(), "personal relationships; \"open door\" policy".to_owned()),
+                                         ("Value to the organization:".to_owned(), "remains stable and predictable; develops a wide range of friendships; listens to others' feelings".to_owned()),
+                                         ("Overuses:".to_owned(), "indirect approach; tolerance".to_owned()),
+                                         ("Under pressure:".to_owned(), "becomes overly flexible and intimate; is too trusting without differentiating among people".to_owned()),
+                                         ("Fears".to_owned(), "pressuring people; being accused of causing harm".to_owned()),
+                                         ("Would increase effectiveness through:".to_owned(), "attention to realistic deadlines; initiative to complete the task".to_owned())],
+                         content: "".to_owned()},
+                 Profile {name: "Creative".to_owned(),
+                         aspects: vec![("Emotions:".to_owned(), "accepts aggression; restrains expression".to_owned()),
+                                         ("Goal:".to_owned(), "dominance; unique accomplishments".to_owned()),
+                                         ("Judges others by:".to_owned(), "personal standards; progressive ideas for accomplishing tasks".to_owned()),
+                                         ("Influences others by:".to_owned(), "ability to pace development of systems and innovative approaches".to_owned()),
+                                         ("Value to the organization:".to_owned(), "initiates or designs change".to_owned()),
+                                         ("Overuses:".to_owned(), "bluntness; critical or condescending attitude".to_owned()),
+                                         ("Under pressure:".to_owned(), "becomes bored with routine work; sulks when restrained; acts independently".to_owned()),
+                                         ("Fears".to_owned(), "lack of influence; failure to achieve their standards".to_owned()),
+                                         ("Would increase effectiveness through:".to_owned(), "warmth; tactful communication; effective team cooperation; recognition of existing sanctions".to_owned())],
+                         content: "".to_owned()},
+                 Profile {name: "Developer".to_owned(),
+                         aspects: vec![("Emotions:".to_owned(), "is concerned with meeting personal needs".to_owned()),
+                                         ("Goal:".to_owned(), "new opportunities".to_owned()),
+                                         ("Judges others by:".to_owned(), "ability to meet the Developer's standards".to_owned()),
+                                         ("Influences others by:".to_owned(), "finding solutions to problems; projecting a personal sense of power".to_owned()),
+                                         ("Value to the organization:".to_owned(), "avoids \"passing the buck\"; seeks new or innovative problem-solving methods".to_owned()),
+                                         ("Overuses:".to_owned(), "control over people and situations to accomplish his or her own results".to_owned()),
+                                         ("Under pressure:".to_owned(), "works alone to complete tasks; is belligerant if individualism is threatened or challenging opportunities disappear".to_owned()),
+                                         ("Fears".to_owned(), "boredom; loss of control".to_owned()),
+                                         ("Would increase effectiveness through:".to_owned(), "patience, empathy; participation and collaboration with others; follow-through and attention to quality control".to_owned())],
+                         content: "".to_owned()},
+                 Profile {name: "Inspirational".to_owned(),
+                         aspects: vec![("Emotions:".to_owned(), "accepts aggression; downplays need for affection".to_owned()),
+                                         ("Goal:".to_owned(), "control of their environment or audience".to_owned()),
+                                         ("Judges others by:".to_owned(), "projection of personal strength, character, and social power".to_owned()),
+                                         ("Influences others by:".to_owned(), "charm, direction, intimidation; use of rewards".to_owned()),
+                                         ("Value to the organization:".to_owned(), "acts as a \"people mover\"; initiates, demands, compliments, disciplines".to_owned()),
+                                         ("Overuses:".to_owned(), "attitude that \"the ends justify the means\"".to_owned()),
+                                         ("Under pressure:".to_owned(), "becomes manipulative, quarrelsome or belligerant".to_owned()),
+                                         ("Fears".to_owned(), "weak behavior; loss of social status".to_owned()),
+                                         ("Would increase effectiveness through:".to_owned(), "genuine sensitivity; willingness to help others to succeed in their own personal development".to_owned())],
+                         content: "".to_owned()},
+                 Profile {name: "Investigator".to_owned(),
+                         aspects: vec![("Emotions:".to_owned(), "is dispassionate; demonstrates self-discipline".to_owned()),
+                                         ("Goal:".to_owned(), "power through formal roles and positions of authority".to_owned()),
+                                         ("Judges others by:".to_owned(), "use of factual information".to_owned()),
+                                         ("Influences others by:".to_owned(), "determination, tenacity".to_owned()),
+                                         ("Value to the organization:".to_owned(), "offers comprehensive follow-through; works determinedly on tasks individuall or in a small group".to_owned()),
+                                         ("Overuses:".to_owned(), "bluntness; suspicion of others".to_owned()),
+                                         ("Under pressure:".to_owned(), "tends to internalize conflict; holds on to grudges".to_owned()),
+                                         ("Fears".to_owned(), "involvement with the masses; responsibility to sell abstract ideas".to_owned()),
+                                         ("Would increase effectiveness through:".to_owned(), "flexibility; acceptance or others; personal involvement with others".to_owned())],
+                         content: "".to_owned()},
+                 Profile {name: "Objective Thinker".to_owned(),
+                         aspects: vec![("Emotions:".to_owned(), "rejects interpersonal aggression".to_owned()),
+                                         ("Goal:".to_owned(), "correctness".to_owned()),
+                                         ("Judges others by:".to_owned(), "ability to think logically".to_owned()),
+                                         ("Influences others by:".to_owned(), "use of facts, data, and logical arguments".to_owned()),
+                                         ("Value to the organization:".to_owned(), "defines and clarifies; obtains, evaluates, and tests information".to_owned()),
+                                         ("Overuses:".to_owned(), "analysis".to_owned()),
+                                         ("Under pressure:".to_owned(), "becomes worrisome".to_owned()),
+                                         ("Fears".to_owned(), "irrational acts; ridicule".to_owned()),
+                                         ("Would increase effectiveness through:".to_owned(), "self-disclosure; public discussion of their insights and opinions".to_owned())],
+                         content: "".to_owned()},
+                 Profile {name: "Perfectionist".to_owned(),
+                         aspects: vec![("Emotions:".to_owned(), "displays competence; is restrained and cautious".to_owned()),
+                                         ("Goal:".to_owned(), "stability; predictble accomplishments".to_owned()),
+                                         ("Judges others by:".to_owned(), "precise standards".to_owned()),
+                                         ("Influences others by:".to_owned(), "attention to detail; accuracy".to_owned()),
+                                         ("Value to the organization:".to_owned(), "is conscientious; maintains standards; controls quality".to_owned()),
+                                         ("Overuses:".to_owned(), "procedures and \"fail-safe\" controls; overdependence on people, products, and processes that have worked in past".to_owned()),
+                                         ("Under pressure:".to_owned(), "becomes tactful and diplomatic".to_owned()),
+                                         ("Fears".to_owned(), "antogonism".to_owned()),
+                                         ("Would increase effectiveness through:".to_owned(), "role flexibility; independence and interdependence; belief in self-worth".to_owned())],
+                         content: "".to_owned()},
+                 Profile {name: "Persuader".to_owned(),
+                         aspects: vec![("Emotions:".to_owned(), "trusts others; is enthusiastic".to_owned()),
+                                         ("Goal:".to_owned(), "authority and prestige; status symbols".to_owned()),
+                                         ("Judges others by:".to_owned(), "ability to express themselves; flexibility".to_owned()),
+                                         ("Influences others by:".to_owned(), "friendly, open manner; verbal skills".to_owned()),
+                                         ("Value to the organization:".to_owned(), "sells and closes; delegates responsibility; is poised and confident".to_owned()),
+                                         ("Overuses:".to_owned(), "enthusiasm; selling ability; optimism".to_owned()),
+                                         ("Under pressure:".to_owned(), "becomes indecisive and is easily persuaded; becomes organized in order to look good".to_owned()),
+                                         ("Fears".to_owned(), "fixed environment; complex relationships".to_owned()),
+                                         ("Would increase effectiveness through:".to_owned(), "challenging assignments; attention to task-oriented service and key details; objective data analysis".to_owned())],
+                         content: "".to_owned()},
+                 Profile {name: "Practitioner".to_owned(),
+                         aspects: vec![("Emotions:".to_owned(), "wants to keep up with others in effort and technical performance".to_owned()),
+                                         ("Goal:".to_owned(), "personal growth".to_owned()),
+                                         ("Judges others by:".to_owned(), "self-discipline; position and promotions".to_owned()),
+                                         ("Influences others by:".to_owned(), "confidence in their ability to master new skills; development of \"proper\" procedures and actions".to_owned()),
+                                         ("Value to the organization:".to_owned(), "is skilled in technical and people problem-solving; displays proficiency and specialization".to_owned()),
+                                         ("Overuses:".to_owned(), "overattention to personal objectives; unrealistic expectations of others".to_owned()),
+                                         ("Under pressure:".to_owned(), "becomes restrained; is sensitive to criticism".to_owned()),
+                                         ("Fears".to_owned(), "being too predictable; no recognition as an \"expert\"".to_owned()),
+                                         ("Would increase effectiveness through:".to_owned(), "genuine collaboration for common benefit; delegation of key tasks to appropriate individuals".to_owned())],
+                         content: "".to_owned()},
+                 Profile {name: "Promoter".to_owned(),
+                         aspects: vec![("Emotions:".to_owned(), "is willing to accept others".to_owned()),
+                                         ("Goal:".to_owned(), "approval, popularity".to_owned()),
+                                         ("Judges others by:".to_owned(), "verbal skills".to_owned()),
+                                         ("Influences others by:".to_owned(), "praise, opportunities, favors".to_owned()),
+                                         ("Value to the organization:".to_owned(), "relieves tension; promotes projects and people, including him or herself".to_owned()),
+                                         ("Overuses:".to_owned(), "praise, optimism".to_owned()),
+                                         ("Under pressure:".to_owned(), "becomes careless and sentimental; is disorganized".to_owned()),
+                                         ("Fears".to_owned(), "loss of social acceptance and self-worth".to_owned()),
+                                         ("Would increase effectiveness through:".to_owned(), "control of time; objectivity; sense of urgency; emotional control; follow-through on promises and tasks".to_owned())],
+                         content: "".to_owned()},
+                 Profile {name: "Result-Oriented".to_owned(),
+                         aspects: vec![("Emotions:".to_owned(), "verbalizes ego strength; displays rugged individualism".to_owned()),
+                                         ("Goal:".to_owned(), "dominance and independence".to_owned()),
+                                         ("Judges others by:".to_owned(), "ability to accomplish tasks quickly".to_owned()),
+                                         ("Influences others by:".to_owned(), "force of character; diligence".to_owned()),
+                                         ("Value to the organization:".to_owned(), "persistence; doggedness".to_owned()),
+                                         ("Overuses:".to_owned(), "impatience; \"win-lose\" competition".to_owned()),
+                                         ("Under pressure:".to_owned(), "becomes critical and fault-finding; resists participating with a team; may overstep boundaries".to_owned()),
+                                         ("Fears".to_owned(), "others with take advantage of them; slowness, especially in task activities; being a pushover".to_owned()),
+                                         ("Would increase effectiveness through:".to_owned(), "explanation of their reasoning and consideration of other views and ideas about goals and solutions to problems; genuine concern for other; patience and humility".to_owned())],
+                         content: "".to_owned()},
+                 Profile {name: "Specialist".to_owned(),
+                         aspects: vec![("Emotions:".to_owned(), "is calculatingly moderate; accommodates others".to_owned()),
+                                         ("Goal:".to_owned(), "maintenance of the status quo; controlled environment".to_owned()),
+                                         ("Judges others by:".to_owned(), "friendship standards; competence".to_owned()),
+                                         ("Influences others by:".to_owned(), "consistent performance; accommodating others".to_owned()),
+                                         ("Value to the organization:".to_owned(), "plans short term; is predictable, consistent; maintains steady pace".to_owned()),
+                                         ("Overuses:".to_owned(), "modesty; low risk-taking; passive resistance to innovation".to_owned()),
+                                         ("Under pressure:".to_owned(), "becomes adaptable to those in authority and think with the group".to_owned()),
+                                         ("Fears".to_owned(), "change, disorganization".to_owned()),
+                                         ("Would increase effectiveness through:".to_owned(), "public discussion of their ideas; self-confidence based on feedback; shortcut methods".to_owned())],
+                         content: "".to_owned()},
+                 Profile {name: "Invalid".to_owned(),
+                         aspects: vec![],
+                         content: "".to_owned()}];
+}
+
 impl TemplateApp {
     /// Called once before the first frame.
     pub fn new(cc: &eframe::CreationContext<'_>) -> Self {
@@ -418,24 +713,88 @@ impl TemplateApp {
 
         // Load previous app state (if any).
         // Note that you must enable the `persistence` feature for this to work.
-        if let Some(storage) = cc.storage {
-            return eframe::get_value(storage, eframe::APP_KEY).unwrap_or_default();
+        let mut app: TemplateApp = match cc.storage {
+            Some(storage) => eframe::get_value(storage, eframe::APP_KEY).unwrap_or_default(),
+            None => Default::default()
+        };
+
+        // Questions aren't persisted, so put the facilitator's chosen form back, unless the instrument lock refuses it.
+        if let Some(form) = app.forms.iter().find(|f| f.name == app.admin.form) {
+
+            if app.admin.allows_instrument(&form.questions) {
+                use_form(form, &mut app.questions, &mut app.responses);
+            } else {
+                app.formStatus = format!("{} is not the locked instrument version", form.name);
+                app.admin.form.clear();
+            }
+        }
+
+        // Edited patterns live in the profile library file, where they can be shared.
+        let library = std::path::Path::new(&app.profileEditor.path);
+
+        if library.exists() {
+            match load_library(library) {
+                Ok(profiles) => app.profiles = profiles,
+                Err(e) => app.profileEditor.status = e
+            }
+        }
+
+        let highlights = std::path::Path::new(&app.highlightsPath);
+
+        if highlights.exists() {
+            match load_highlights(highlights) {
+                Ok(h) => app.highlights = h,
+                Err(e) => app.highlightsStatus = e
+            }
         }
 
-        Default::default()
+        return app;
+    }
+
+    /// Like `new`, but for a shared machine: each participant starts at the instructions, their session is saved
+    /// under their name when they finish, and Settings and earlier results stay hidden unless a facilitator unlocks admin mode.
+    pub fn new_kiosk(cc: &eframe::CreationContext<'_>) -> Self {
+
+        let mut app = Self::new(cc);
+        app.kiosk = true;
+        app.currentPage = Page::Response;
+        app.sessionName.clear();
+
+        return app;
     }
 }
 
 impl eframe::App for TemplateApp {
     /// Called by the frame work to save state before shutdown.
     fn save(&mut self, storage: &mut dyn eframe::Storage) {
+
+        // With encryption on, sessions only reach storage sealed; never fall back to plaintext.
+        if let Some(key) = &self.vaultKey {
+
+            match key.seal(&self.sessions) {
+                Ok(sealed) => self.sealedSessions = Some(sealed),
+                Err(_) => return
+            }
+
+            let sessions = std::mem::take(&mut self.sessions);
+            eframe::set_value(storage, eframe::APP_KEY, self);
+            self.sessions = sessions;
+            return;
+        }
+
         eframe::set_value(storage, eframe::APP_KEY, self);
     }
 
+    fn auto_save_interval(&self) -> std::time::Duration {
+
+        // A kiosk may be switched off without closing the program.
+        return std::time::Duration::from_secs(if self.kiosk { 5 } else { 30 });
+    }
+
     /// Called each time the UI needs repainting, which may be many times per second.
     /// Put your widgets into a `SidePanel`, `TopPanel`, `CentralPanel`, `Window` or `Area`.
     fn update(&mut self, ctx: &egui::Context, _frame: &mut eframe::Frame) {
-        let Self { fontSizes, currentPage, currentHighlight, graphType, questions , responses , tally, intensity, profiles, currentProfile} = self;
+        let Self { fontSizes, currentPage, currentHighlight, graphType, questions , responses , responseStarted, responseEvents, focus, focusConfirmed, responseShuffle, forms, formPath, formStatus, editorForm, editorPath, editorStatus, editorPreview, tally, intensity, profiles, currentProfile, profileEditor, descriptorTicks, reflection, actionDraft, highlights, highlightsPath, highlightsStatus, sessions, sessionName, comparison, historyPerson, historyFocus, exportPath, exportWidth, exportStatus, reportPath, reportStatus, admin, adminUnlocked, passphraseInput, adminStatus, releasedResponses, confirmClear, datasetPath, datasetCoarsen, datasetSalt, datasetStatus, paperSheetPath, paperKeyPath, batchInput, batchOutput, batchStatus, sealedSessions, vaultKey, vaultPassphrase, vaultStatus, kiosk, collab} = self;
 
         // Examples of how to create different panels and windows.
         // Pick whichever suits you.
@@ -472,26 +831,97 @@ impl eframe::App for TemplateApp {
             ui.heading("DiSC Program");
             ui.separator();
 
-            ui.horizontal(|ui| {
-                if ui.selectable_label(*currentPage == Page::Response, "Response Page").clicked() { *currentPage = Page::Response; }
-                if ui.selectable_label(*currentPage == Page::Results, "Results Page").clicked() { *currentPage = Page::Results; process(responses, tally, intensity); }
-                if ui.selectable_label(*currentPage == Page::Settings, "Settings Page").clicked() { *currentPage = Page::Settings; }
-            });
+            if sealedSessions.is_some() && vaultKey.is_none() {
+                show_vault_unlock(sealedSessions, vaultKey, sessions, vaultPassphrase, vaultStatus, (admin, adminUnlocked, passphraseInput, adminStatus), *kiosk, ui);
+                return;
+            }
+
+            // Without a passphrase there is nobody to hide anything from, except at a kiosk.
+            let isAdmin = if *kiosk { *adminUnlocked } else { !admin.is_protected() || *adminUnlocked };
+
+            if (*currentPage == Page::Settings || *currentPage == Page::History || *currentPage == Page::Editor || *currentPage == Page::Profiles || *currentPage == Page::Statistics) && !isAdmin { *currentPage = Page::Response; }
+
+            if !*kiosk || isAdmin {
+                ui.horizontal(|ui| {
+                    if ui.selectable_label(*currentPage == Page::Response, "Response Page").clicked() { *currentPage = Page::Response; }
+                    if ui.selectable_label(*currentPage == Page::Results, "Results Page").clicked() { *currentPage = Page::Results; process(responses, tally, intensity); }
+                    if isAdmin && ui.selectable_label(*currentPage == Page::Settings, "Settings Page").clicked() { *currentPage = Page::Settings; }
+                    if isAdmin && ui.selectable_label(*currentPage == Page::History, "History").clicked() { *currentPage = Page::History; }
+                    if isAdmin && ui.selectable_label(*currentPage == Page::Statistics, "Statistics").clicked() { *currentPage = Page::Statistics; }
+                    if isAdmin && ui.selectable_label(*currentPage == Page::Editor, "Instrument Editor").clicked() { *currentPage = Page::Editor; }
+                    if isAdmin && ui.selectable_label(*currentPage == Page::Profiles, "Profile Editor").clicked() { *currentPage = Page::Profiles; }
+                    if ui.selectable_label(*currentPage == Page::Team, "Team Session").clicked() { *currentPage = Page::Team; }
+                    if ui.selectable_label(*currentPage == Page::Admin, if *adminUnlocked { "Admin 🔓" } else { "Admin 🔒" }).clicked() { *currentPage = Page::Admin; }
+                });
+            } else if *currentPage == Page::Admin {
+                if ui.button("Back").clicked() { *currentPage = Page::Response; }
+            }
+
+            if *kiosk && *currentPage == Page::Results && ui.button("Finish").on_hover_text("Clears your answers for the next person").clicked() {
+
+                responses.iter_mut().for_each(|r| *r = (Choice::NONE, Choice::NONE));
+                *responseStarted = None;
+                *responseShuffle = None;
+                responseEvents.clear();
+                focus.clear();
+                *focusConfirmed = false;
+                *tally = (0, 0, 0, 0);
+                *intensity = (1, 1, 1, 1);
+                sessionName.clear();
+                *currentHighlight = Choice::NONE;
+                *descriptorTicks = Default::default();
+                *reflection = Reflection::default();
+                *actionDraft = ("".to_owned(), "".to_owned(), "".to_owned());
+                *currentPage = Page::Response;
+
+                // Reopens the instructions and scrolls back to the top.
+                ctx.memory_mut(|m| *m = Default::default());
+            }
 
             match *currentPage {
                 Page::Response => show_response_instructions(ui),
                 _ => ()
             }
 
+            if *kiosk && !isAdmin && admin.is_protected() && *currentPage == Page::Response {
+                ui.with_layout(egui::Layout::right_to_left(egui::Align::Min), |ui| {
+                    if ui.small_button("Facilitator").clicked() { *currentPage = Page::Admin; }
+                });
+            }
+
             ui.separator();
             ui.add_space(10.0);
 
             ui.set_min_width(ui.available_width());
 
+            let before = *currentPage;
+
             match currentPage {
-                Page::Response => show_response_page(currentPage, questions, responses, tally, intensity, ctx, ui),
-                Page::Results => show_results_page(currentHighlight, profiles, currentProfile, graphType, tally, intensity, ui),
-                Page::Settings => show_settings_page(fontSizes, ui)
+                Page::Response if !*focusConfirmed => show_focus_step(focus, focusConfirmed, ui),
+                Page::Response => show_response_page(currentPage, questions, responses, responseStarted, responseEvents, focus, focusConfirmed, responseShuffle, (admin.shuffle_groups, admin.shuffle_words), &admin.form, tally, intensity, *kiosk, sessionName, sessions, ctx, ui),
+                Page::Results => {
+
+                    if isAdmin || !admin.hide_until_reviewed || releasedResponses == responses {
+
+                        let visible = if isAdmin { ResultSections::default() } else { admin.visible };
+                        show_results_page(currentHighlight, profiles, currentProfile, highlights, descriptorTicks, reflection, actionDraft, graphType, questions, responses, tally, intensity, sessions, sessionName, comparison, exportPath, exportWidth, exportStatus, reportPath, reportStatus, responseStarted, responseEvents, focus, responseShuffle, &admin.form, isAdmin, visible, ui);
+                    } else {
+                        ui.label("Thank you, your responses have been recorded.");
+                        ui.label("Your facilitator will review your results with you.");
+                    }
+                },
+                Page::Settings => show_settings_page(fontSizes, ui),
+                Page::Admin => show_admin_page(admin, adminUnlocked, passphraseInput, adminStatus, releasedResponses, confirmClear, questions, responses, responseStarted, responseEvents, responseShuffle, forms, formPath, formStatus, (paperSheetPath, paperKeyPath), sessions, profiles, (datasetPath, datasetCoarsen, datasetSalt, datasetStatus), (batchInput, batchOutput, batchStatus), highlights, *graphType, sealedSessions, vaultKey, vaultPassphrase, vaultStatus, ui),
+                Page::Team => show_team_page(collab, isAdmin, ctx, ui),
+                Page::History => show_history_page(historyPerson, historyFocus, sessions, profiles, ui),
+                Page::Statistics => show_statistics_page(sessions, profiles, questions, &admin.form, ui),
+                Page::Editor => show_editor_page(editorForm, editorPath, editorStatus, editorPreview, forms, &admin.form, ui),
+                Page::Profiles => show_profile_editor_page(profiles, profileEditor, highlights, highlightsPath, highlightsStatus, ui)
+            }
+
+            // Pressing Next on the Response Page hands a joined participant's answers to the facilitator.
+            if before == Page::Response && *currentPage == Page::Results {
+                collab.submit(&form_label(&admin.form), questions, &chosen_words(questions, &ItemRecord { responses, events: responseEvents }), ctx);
             }
         });
 
@@ -506,7 +936,21 @@ impl eframe::App for TemplateApp {
     }
 }
 
-fn show_response_page(currentPage: &mut Page, questions: &mut Vec<Vec<(String, Choice, Choice)>>, responses: &mut Vec<(Choice, Choice)>, tally: &mut (i8, i8, i8, i8), intensity: &mut (i8, i8, i8, i8), ctx: &Context, ui: &mut Ui) {
+fn show_response_page(currentPage: &mut Page, questions: &mut Vec<Vec<(String, Choice, Choice)>>, responses: &mut Vec<(Choice, Choice)>, responseStarted: &mut Option<DateTime<Utc>>, responseEvents: &mut Vec<ResponseEvent>, focus: &mut String, focusConfirmed: &mut bool, responseShuffle: &mut Option<Shuffle>, shuffle: (bool, bool), form: &String, tally: &mut (i8, i8, i8, i8), intensity: &mut (i8, i8, i8, i8), kiosk: bool, sessionName: &mut String, sessions: &mut Vec<Session>, ctx: &Context, ui: &mut Ui) {
+
+    // Timing and any shuffling start the first time the answer sheet is on screen.
+    if responseStarted.is_none() {
+        *responseStarted = Some(Utc::now());
+        *responseShuffle = Shuffle::new(shuffle.0, shuffle.1);
+    }
+
+    ui.horizontal(|ui| {
+        ui.label(RichText::new("Focus").color(Color32::from_rgb(137, 207, 240)));
+        ui.label(focus.to_owned());
+        if ui.small_button("Change").clicked() { *focusConfirmed = false; }
+    });
+
+    ui.add_space(5.0);
 
     egui::Grid::new("Response Page ".to_owned())
         .spacing(Vec2 {x: 10.0, y: 0.0})
@@ -529,18 +973,34 @@ fn show_response_page(currentPage: &mut Page, questions: &mut Vec<Vec<(String, C
         .min_row_height(4.0)
         .show(ui, |ui|{
 
-            for i in 0..questions.len() {
+            // Answers stay in the instrument's own order; only what is shown is shuffled.
+            let layout = Layout::new(*responseShuffle, questions);
+
+            // Selections follow the words clicked rather than their keys, so a word keyed the same as
+            // an unanswered column (or as another word) can still be picked and is still recorded.
+            let chosen = chosen_words(questions, &ItemRecord { responses, events: responseEvents });
+
+            for (k, &i) in layout.groups.iter().enumerate() {
                 
-                ui.label(RichText::new((i + 1).to_string().to_owned()).strong().color(Color32::from_rgb(137, 207, 240)));
+                ui.label(RichText::new((k + 1).to_string().to_owned()).strong().color(Color32::from_rgb(137, 207, 240)));
 
-                for j in 0..questions[i].len() {
+                for &j in &layout.words[i] {
 
                     ui.end_row();
                     ui.add_space(10.0);
                     ui.hyperlink_to(questions[i][j].0.to_owned(), "https://www.dictionary.com/browse/".to_owned() + &questions[i][j].0.to_owned());
                     ui.add_space(10.0);
-                    ui.radio_value(&mut responses[i].0, questions[i][j].1, "");
-                    ui.radio_value(&mut responses[i].1, questions[i][j].2, "");
+
+                    if ui.radio(chosen[i].0 == Some(j), "").clicked() && chosen[i].0 != Some(j) {
+                        responses[i].0 = questions[i][j].1;
+                        responseEvents.push(ResponseEvent { group: i, word: questions[i][j].0.to_owned(), column: Column::Most, at: Utc::now() });
+                    }
+
+                    if ui.radio(chosen[i].1 == Some(j), "").clicked() && chosen[i].1 != Some(j) {
+                        responses[i].1 = questions[i][j].2;
+                        responseEvents.push(ResponseEvent { group: i, word: questions[i][j].0.to_owned(), column: Column::Least, at: Utc::now() });
+                    }
+
                     ui.end_row();
                 }
 
@@ -551,7 +1011,22 @@ fn show_response_page(currentPage: &mut Page, questions: &mut Vec<Vec<(String, C
 
         ui.with_layout(egui::Layout::right_to_left(egui::Align::Max), |ui| {
             ui.add_space(50.0);
-            if ui.button("Next").clicked() { *currentPage = Page::Results; process(responses, tally, intensity); }
+
+            // At a kiosk the session is saved as soon as the participant finishes, under the name they type.
+            if ui.add_enabled(!kiosk || !sessionName.trim().is_empty(), egui::Button::new("Next")).clicked() {
+
+                *currentPage = Page::Results;
+                process(responses, tally, intensity);
+
+                if kiosk {
+                    sessions.push(Session { name: sessionName.trim().to_owned(), completed: Some(Utc::now()), focus: focus.to_owned(), form: form_label(form), shuffle: *responseShuffle, responses: responses.clone(), tally: *tally, intensity: *intensity, started: *responseStarted, events: responseEvents.clone(), descriptors: Default::default(), reflection: Reflection::default() });
+                }
+            }
+
+            if kiosk {
+                ui.text_edit_singleline(sessionName);
+                ui.label("Your Name");
+            }
         });
 
         ui.add_space(20.0);
@@ -696,7 +1171,7 @@ fn show_response_instructions(ui: &mut Ui) {
 
 }
 
-fn show_results_page(currentHighlight: &mut Choice, profiles: &mut Vec<Profile>, currentProfile: &mut Profile, graphType: &mut GraphType, tally: &mut (i8, i8, i8, i8), intensity: &mut (i8, i8, i8, i8), ui: &mut Ui) {
+fn show_results_page(currentHighlight: &mut Choice, profiles: &mut Vec<Profile>, currentProfile: &mut Profile, highlights: &Vec<DimensionHighlights>, descriptorTicks: &mut [Vec<String>; 4], reflection: &mut Reflection, actionDraft: &mut (String, String, String), graphType: &mut GraphType, questions: &Vec<Vec<(String, Choice, Choice)>>, responses: &mut Vec<(Choice, Choice)>, tally: &mut (i8, i8, i8, i8), intensity: &mut (i8, i8, i8, i8), sessions: &mut Vec<Session>, sessionName: &mut String, comparison: &mut Comparison, exportPath: &mut String, exportWidth: &mut u32, exportStatus: &mut String, reportPath: &mut String, reportStatus: &mut String, responseStarted: &Option<DateTime<Utc>>, responseEvents: &Vec<ResponseEvent>, focus: &String, responseShuffle: &Option<Shuffle>, form: &String, isAdmin: bool, visible: ResultSections, ui: &mut Ui) {
 
     egui::ScrollArea::vertical().show(ui, |ui| {      
 
@@ -769,6 +1244,12 @@ fn show_results_page(currentHighlight: &mut Choice, profiles: &mut Vec<Profile>,
             ]
         };
 
+        // Everyone else's saved results make up the norm group.
+        let normIntensity: [Vec<f64>; 4] = [0, 1, 2, 3].map(|d| sessions.iter()
+            .filter(|s| !(s.started.is_some() && s.started == *responseStarted))
+            .map(|s| [s.intensity.0, s.intensity.1, s.intensity.2, s.intensity.3][d] as f64)
+            .collect());
+
         ui.set_min_width(ui.available_width());
         egui::Grid::new("Numbers")
             .num_columns(5)
@@ -793,6 +1274,8 @@ fn show_results_page(currentHighlight: &mut Choice, profiles: &mut Vec<Profile>,
 
                 ui.end_row();
 
+                if !visible.intensities { return; }
+
                 ui.label(RichText::new("Intensity"));
                 ui.label(&intensity.0.to_string());
                 ui.label(&intensity.1.to_string());
@@ -808,123 +1291,1872 @@ fn show_results_page(currentHighlight: &mut Choice, profiles: &mut Vec<Profile>,
                 ui.label(&intensity_to_segment(intensity.3).to_string());
 
                 ui.end_row();
-            });
 
-        ui.add_space(25.0);
-        ui.set_min_width(ui.available_width() - 100.0);
+                if normIntensity[0].len() >= NORM_MIN_RESULTS {
 
-        match currentHighlight {
+                    ui.label(RichText::new("Percentile")).on_hover_text(format!("Intensity ranked against {} saved results", normIntensity[0].len()));
 
-            Choice::A => show_d_highlights(ui),
-            Choice::B => show_i_highlights(ui),
-            Choice::C => show_s_highlights(ui),
-            Choice::D => show_c_highlights(ui),
-            _ => ()
+                    for (norm, v) in normIntensity.iter().zip([intensity.0, intensity.1, intensity.2, intensity.3]) {
+                        ui.label(format!("{:.0}", percentile_rank(norm, v as f64)));
+                    }
 
-        }
+                    ui.end_row();
+                }
+            });
 
-        ui.separator();
+        if visible.intensities {
 
-        ui.collapsing(RichText::new("Graph").strong().color(Color32::from_rgb(137, 207, 240)), |ui| {
+            ui.collapsing(RichText::new("Segment Descriptors").strong().color(Color32::from_rgb(137, 207, 240)), |ui| {
 
-            ui.add_space(10.0);
+                if show_descriptor_chart(highlights, *intensity, descriptorTicks, ui) {
 
-            ui.horizontal_wrapped(|ui| {
+                    // A session saved before the words were ticked picks them up too.
+                    if let Some(s) = sessions.iter_mut().rev().find(|s| s.started.is_some() && s.started == *responseStarted) {
+                        s.descriptors = descriptorTicks.clone();
+                    }
+                }
+            });
+        }
 
-                if ui.selectable_label(*graphType == GraphType::Line, "Line").clicked() { *graphType = GraphType::Line; }
-                if ui.selectable_label(*graphType == GraphType::Bar, "Bar").clicked() { *graphType = GraphType::Bar; }
+        ui.add_space(10.0);
 
-            });
-            ui.add_space(20.0);
+        if isAdmin {
 
-            Plot::new("Graph")
-                .data_aspect(6.5 / 28.0)
-                .view_aspect(0.75)
-                .show_x(false)
-                .show_y(false)
-                .height(500.0)
-                .allow_drag(false)
-                .allow_zoom(false)
-                .allow_scroll(false)
-                .allow_boxed_zoom(false)
-                .show_background(false)
-                .x_axis_formatter(x_fmt)
-                .y_axis_formatter(y_fmt)
-                .y_grid_spacer(y_spacer)
-                .show(ui, |plot_ui| {
-
-                    let series: PlotPoints = PlotPoints::Owned(vec![PlotPoint::new(1, intensity.0), PlotPoint::new(2, intensity.1), PlotPoint::new(3, intensity.2), PlotPoint::new(4, intensity.3)]);
-                    let line: egui::plot::Line = Line::new(series);      
-
-                    let dBar: egui::plot::Bar = Bar::new(1.0, intensity.0 as f64);
-                    let iBar: egui::plot::Bar = Bar::new(2.0, intensity.1 as f64);
-                    let sBar: egui::plot::Bar = Bar::new(3.0, intensity.2 as f64);
-                    let cBar: egui::plot::Bar = Bar::new(4.0, intensity.3 as f64);
-
-                    let barChart: egui::plot::BarChart = BarChart::new(vec![dBar, iBar, sBar, cBar]);   
-
-                    match graphType {
-                        GraphType::Line => plot_ui.line(line),
-                        GraphType::Bar => plot_ui.bar_chart(barChart)
-                    }
+            let normTimes: Vec<f64> = sessions.iter().filter_map(|s| completion_time(s.started, &s.events, s.responses.len())).collect();
+            let sheet = SheetRecord { responses, tally: *tally, started: *responseStarted, events: responseEvents, shuffle: *responseShuffle };
 
-                    plot_ui.set_plot_bounds(PlotBounds::from_min_max([0.0, 0.0], [4.5, 28.0]));
-                });
-        });
+            show_quality_badges(&quality_flags(questions, &sheet, &normTimes), ui);
 
-        ui.add_space(10.0);
-        ui.separator();
+            ui.horizontal(|ui| {
 
-        ui.collapsing(RichText::new("Profile Pattern").strong().color(Color32::from_rgb(137, 207, 240)), |ui| {
+                ui.label("Name");
+                ui.text_edit_singleline(sessionName);
 
-            let seg: (i8, i8, i8, i8) = (intensity_to_segment(intensity.0), intensity_to_segment(intensity.1), intensity_to_segment(intensity.2), intensity_to_segment(intensity.3));
+                if ui.add_enabled(!sessionName.trim().is_empty(), egui::Button::new("Save Session")).clicked() {
 
-            *currentProfile = profilePatternLookup(profiles, seg);
-            show_profile_section(currentProfile, ui);
+                    sessions.push(Session { name: sessionName.trim().to_owned(), completed: Some(Utc::now()), focus: focus.to_owned(), form: form_label(form), shuffle: *responseShuffle, responses: responses.clone(), tally: *tally, intensity: *intensity, started: *responseStarted, events: responseEvents.clone(), descriptors: descriptorTicks.clone(), reflection: reflection.clone() });
+                    sessionName.clear();
+                }
+            });
+
+            // The web build has nowhere to write files.
+            #[cfg(not(target_arch = "wasm32"))]
+            {
+                ui.horizontal(|ui| {
+
+                    ui.label("Report");
+                    ui.text_edit_singleline(reportPath);
+
+                    if ui.button("Save Report").on_hover_text("Saves the results as a single HTML page").clicked() {
+
+                        let seg = (intensity_to_segment(intensity.0), intensity_to_segment(intensity.1), intensity_to_segment(intensity.2), intensity_to_segment(intensity.3));
+                        let name = if sessionName.trim().is_empty() { "Participant" } else { sessionName.trim() };
+
+                        let report = crate::report::Report { name, focus, tally: *tally, intensity: *intensity, graphType: *graphType, profile: &profilePatternLookup(profiles, seg), highlights, reflection };
+
+                        *reportStatus = match report.write(std::path::Path::new(reportPath.trim())) {
+                            Ok(()) => format!("Saved {}", reportPath.trim()),
+                            Err(e) => e
+                        };
+                    }
+
+                    ui.small(reportStatus.to_owned());
+                });
+            }
+        }
+
+        ui.add_space(25.0);
+        ui.set_min_width(ui.available_width() - 100.0);
+
+        if visible.highlights {
+
+            let seg = [intensity_to_segment(intensity.0), intensity_to_segment(intensity.1), intensity_to_segment(intensity.2), intensity_to_segment(intensity.3)];
+
+            egui::CollapsingHeader::new(RichText::new("What Your Segments Mean").strong().color(Color32::from_rgb(137, 207, 240)))
+                .default_open(true)
+                .show(ui, |ui| {
+
+                    for (h, segment) in highlights.iter().zip(seg) {
+
+                        if let Some(n) = h.narrative(segment) {
+
+                            ui.horizontal_wrapped(|ui| {
+                                ui.small(RichText::new(format!("{} (segment {})", n.label, segment)).strong());
+                                ui.small(n.text.to_owned());
+                            });
+                        }
+                    }
+                });
+
+            ui.add_space(10.0);
+
+            match currentHighlight {
+
+                Choice::A => show_highlights(&highlights[0], seg[0], ui),
+                Choice::B => show_highlights(&highlights[1], seg[1], ui),
+                Choice::C => show_highlights(&highlights[2], seg[2], ui),
+                Choice::D => show_highlights(&highlights[3], seg[3], ui),
+                _ => ()
+
+            }
+        }
+
+        if isAdmin {
+
+            ui.separator();
+
+            ui.collapsing(RichText::new("Response Timeline").strong().color(Color32::from_rgb(137, 207, 240)), |ui| {
+                show_timeline(*responseStarted, responseEvents, responses.len(), ui);
+            });
+        }
+
+        ui.separator();
+
+        if visible.graph {
+            ui.collapsing(RichText::new("Graph").strong().color(Color32::from_rgb(137, 207, 240)), |ui| {
+
+                ui.add_space(10.0);
+
+                if let Comparison::Session(k) = *comparison { if k >= sessions.len() { *comparison = Comparison::NONE; } }
+                if *comparison == Comparison::TeamAverage && sessions.is_empty() { *comparison = Comparison::NONE; }
+
+                // Other people's sessions are only for the facilitator's eyes.
+                if !isAdmin { *comparison = Comparison::NONE; }
+
+                ui.horizontal_wrapped(|ui| {
+
+                    if ui.selectable_label(*graphType == GraphType::Line, "Line").clicked() { *graphType = GraphType::Line; }
+                    if ui.selectable_label(*graphType == GraphType::Bar, "Bar").clicked() { *graphType = GraphType::Bar; }
+                    if ui.selectable_label(*graphType == GraphType::Radar, "Radar").clicked() { *graphType = GraphType::Radar; }
+                    if ui.selectable_label(*graphType == GraphType::Circle, "DiSC Map").clicked() { *graphType = GraphType::Circle; }
+
+                    if !isAdmin { return; }
+
+                    ui.add_space(20.0);
+                    ui.label("Compare with");
+
+                    egui::ComboBox::from_id_source("Comparison")
+                        .selected_text(comparison_name(*comparison, sessions))
+                        .show_ui(ui, |ui| {
+
+                            ui.selectable_value(comparison, Comparison::NONE, comparison_name(Comparison::NONE, sessions));
+
+                            for k in 0..sessions.len() {
+                                ui.selectable_value(comparison, Comparison::Session(k), comparison_name(Comparison::Session(k), sessions));
+                            }
+
+                            if !sessions.is_empty() {
+                                ui.selectable_value(comparison, Comparison::TeamAverage, comparison_name(Comparison::TeamAverage, sessions));
+                            }
+                        });
+                });
+                ui.add_space(20.0);
+
+                // (name, tally, intensity) of every series drawn on the graph, the participant first.
+                let mut series: Vec<(String, [f64; 4], [f64; 4])> = vec![("Current".to_owned(), to_array(*tally), to_array(*intensity))];
+
+                match *comparison {
+                    Comparison::Session(k) => series.push((sessions[k].name.to_owned(), to_array(sessions[k].tally), to_array(sessions[k].intensity))),
+                    Comparison::TeamAverage => {
+
+                        let mut t = [0.0; 4];
+                        let mut v = [0.0; 4];
+
+                        for session in sessions.iter() {
+                            for d in 0..4 {
+                                t[d] += to_array(session.tally)[d] / sessions.len() as f64;
+                                v[d] += to_array(session.intensity)[d] / sessions.len() as f64;
+                            }
+                        }
+
+                        series.push((comparison_name(Comparison::TeamAverage, sessions), t, v));
+                    },
+                    Comparison::NONE => ()
+                }
+
+                let label_series = series.clone();
+                let label_fmt = move |name: &str, value: &PlotPoint| {
+
+                    let x = value.x.round();
+
+                    if x < 1.0 || x > 4.0 {
+                        return String::new();
+                    }
+
+                    let d = x as usize - 1;
+                    let s = label_series.iter().find(|s| s.0 == name).unwrap_or(&label_series[0]);
+
+                    return format!("{} ({})\nTally: {}\nIntensity: {}\nSegment: {}", s.0, ["D", "i", "S", "C"][d], round_to_tenth(s.1[d]), round_to_tenth(s.2[d]), intensity_to_segment(s.2[d].round() as i8));
+                };
+
+                let colors = [Color32::from_rgb(137, 207, 240), Color32::from_rgb(240, 170, 137)];
+
+                if *graphType == GraphType::Line || *graphType == GraphType::Bar {
+
+                    Plot::new("Graph")
+                        .data_aspect(6.5 / 28.0)
+                        .view_aspect(0.75)
+                        .show_x(false)
+                        .show_y(true)
+                        .height(500.0)
+                        .allow_drag(false)
+                        .allow_zoom(false)
+                        .allow_scroll(false)
+                        .allow_boxed_zoom(false)
+                        .show_background(false)
+                        .legend(Legend::default())
+                        .label_formatter(label_fmt)
+                        .x_axis_formatter(x_fmt)
+                        .y_axis_formatter(y_fmt)
+                        .y_grid_spacer(y_spacer)
+                        .show(ui, |plot_ui| {
+
+                            // Shade every other segment and mark the midline, like the paper graph.
+                            for seg in 1..=7 {
+
+                                let low = (seg as f64 - 1.0) * 4.0 + 0.5;
+                                let high = seg as f64 * 4.0 + 0.5;
+
+                                if seg % 2 == 0 {
+                                    plot_ui.polygon(Polygon::new(PlotPoints::new(vec![[0.5, low], [4.5, low], [4.5, high], [0.5, high]])).color(Color32::GRAY).fill_alpha(0.08).width(0.0));
+                                }
+
+                                plot_ui.text(Text::new(PlotPoint::new(4.45, (low + high) / 2.0), RichText::new(seg.to_string()).small()).color(Color32::GRAY).anchor(Align2::RIGHT_CENTER));
+                            }
+
+                            plot_ui.hline(HLine::new(14.5).color(Color32::GRAY).style(LineStyle::dashed_loose()).width(1.0));
+
+                            for (k, s) in series.iter().enumerate() {
+
+                                let points: Vec<[f64; 2]> = (0..4).map(|d| [d as f64 + 1.0, s.2[d]]).collect();
+
+                                // Bars sit side by side when a comparison is shown.
+                                let width = 0.7 / series.len() as f64;
+                                let offset = if *graphType == GraphType::Bar { (k as f64 - (series.len() as f64 - 1.0) / 2.0) * width } else { 0.0 };
+
+                                match graphType {
+                                    GraphType::Line => {
+
+                                        plot_ui.line(Line::new(PlotPoints::new(points.clone())).color(colors[k]).name(s.0.to_owned()));
+                                        plot_ui.points(Points::new(PlotPoints::new(points.clone())).radius(4.0).color(colors[k]).name(s.0.to_owned()));
+                                    },
+                                    GraphType::Bar => {
+
+                                        let bars: Vec<Bar> = points.iter().map(|p| Bar::new(p[0] + offset, p[1]).width(width)).collect();
+                                        let bar_series = s.clone();
+
+                                        plot_ui.bar_chart(BarChart::new(bars).color(colors[k]).name(s.0.to_owned()).element_formatter(Box::new(move |bar, _chart| {
+
+                                            let d = (bar.argument.round() as usize).clamp(1, 4) - 1;
+                                            return format!("{} ({})\nTally: {}\nIntensity: {}\nSegment: {}", bar_series.0, ["D", "i", "S", "C"][d], round_to_tenth(bar_series.1[d]), round_to_tenth(bar_series.2[d]), intensity_to_segment(bar_series.2[d].round() as i8));
+                                        })));
+                                    },
+                                    // Drawn by `show_map_plot`.
+                                    GraphType::Radar | GraphType::Circle => ()
+                                }
+
+                                for p in &points {
+                                    plot_ui.text(Text::new(PlotPoint::new(p[0] + offset, p[1] + 1.0), round_to_tenth(p[1]).to_string()).color(colors[k]).anchor(Align2::CENTER_BOTTOM));
+                                }
+                            }
+
+                            plot_ui.set_plot_bounds(PlotBounds::from_min_max([0.0, 0.0], [4.5, 28.0]));
+                        });
+                } else {
+                    show_map_plot(*graphType, &series, &colors, ui);
+                }
+
+                // The web build has nowhere to write files.
+                #[cfg(not(target_arch = "wasm32"))]
+                if isAdmin {
+                    ui.add_space(10.0);
+
+                    ui.horizontal_wrapped(|ui| {
+
+                        ui.label("Export to");
+                        ui.text_edit_singleline(exportPath);
+                        ui.add(egui::DragValue::new(exportWidth).clamp_range(200..=8000).suffix(" px wide"));
+
+                        if ui.button("Export").on_hover_text("Saves the graph as .svg or .png").clicked() {
+
+                            *exportStatus = match crate::graph::write(std::path::Path::new(exportPath.trim()), *graphType, *intensity, *exportWidth) {
+                                Ok(()) => format!("Saved {}", exportPath.trim()),
+                                Err(e) => e
+                            };
+                        }
+                    });
+
+                    ui.small(exportStatus.to_owned());
+                }
+            });
+        }
+
+        ui.add_space(10.0);
+        ui.separator();
+
+        let seg: (i8, i8, i8, i8) = (intensity_to_segment(intensity.0), intensity_to_segment(intensity.1), intensity_to_segment(intensity.2), intensity_to_segment(intensity.3));
+
+        if visible.profile {
+
+            ui.collapsing(RichText::new("Profile Pattern").strong().color(Color32::from_rgb(137, 207, 240)), |ui| {
+
+                *currentProfile = profilePatternLookup(profiles, seg);
+                show_profile_section(currentProfile, ui);
+            });
+        }
+
+        ui.collapsing(RichText::new("Reflections and Action Plan").strong().color(Color32::from_rgb(137, 207, 240)), |ui| {
+
+            let pattern = if visible.profile { Some(profilePatternLookup(profiles, seg)) } else { None };
+
+            if show_reflection_section(reflection, actionDraft, pattern.as_ref(), ui) {
+
+                // Keep a session saved before the notes were written up to date.
+                if let Some(s) = sessions.iter_mut().rev().find(|s| s.started.is_some() && s.started == *responseStarted) {
+                    s.reflection = reflection.clone();
+                }
+            }
+        });
+    });
+}
+
+/// Free-text notes, the pattern aspects that ring true and a dated action plan. Returns whether anything changed.
+fn show_reflection_section(reflection: &mut Reflection, actionDraft: &mut (String, String, String), pattern: Option<&Profile>, ui: &mut Ui) -> bool {
+
+    let before = reflection.clone();
+
+    ui.add_space(5.0);
+    ui.label("Your reactions to these results");
+    ui.add(egui::TextEdit::multiline(&mut reflection.notes).desired_rows(5).desired_width(f32::INFINITY));
+
+    if let Some(p) = pattern {
+
+        ui.add_space(10.0);
+        ui.label(format!("Which parts of the {} Pattern ring true?", p.name));
+
+        for (label, value) in &p.aspects {
+
+            let mut resonant = reflection.resonant.contains(label);
+
+            ui.horizontal_wrapped(|ui| {
+
+                if ui.checkbox(&mut resonant, RichText::new(label.to_owned()).strong()).changed() {
+                    if resonant { reflection.resonant.push(label.to_owned()); } else { reflection.resonant.retain(|l| l != label); }
+                }
+
+                ui.small(value.to_owned());
+            });
+        }
+    }
+
+    ui.add_space(10.0);
+    ui.label("Action Plan");
+
+    let mut removed: Option<usize> = None;
+
+    egui::Grid::new("Action Plan").num_columns(4).striped(true).show(ui, |ui| {
+
+        for (k, action) in reflection.actions.iter_mut().enumerate() {
+
+            ui.checkbox(&mut action.done, "");
+            ui.add(egui::TextEdit::singleline(&mut action.text).desired_width(300.0));
+            ui.label(action.due.map(|d| d.format("%Y-%m-%d").to_string()).unwrap_or("No date".to_owned()));
+            if ui.small_button("✖").on_hover_text("Remove this goal").clicked() { removed = Some(k); }
+            ui.end_row();
+        }
+    });
+
+    if let Some(k) = removed { reflection.actions.remove(k); }
+
+    ui.horizontal(|ui| {
+
+        ui.label("Goal");
+        ui.add(egui::TextEdit::singleline(&mut actionDraft.0).desired_width(300.0));
+        ui.label("Due");
+        ui.add(egui::TextEdit::singleline(&mut actionDraft.1).desired_width(100.0).hint_text("YYYY-MM-DD"));
+
+        if ui.add_enabled(!actionDraft.0.trim().is_empty(), egui::Button::new("Add")).clicked() {
+
+            match parse_due(&actionDraft.1) {
+                Ok(due) => {
+                    reflection.actions.push(ActionItem { text: actionDraft.0.trim().to_owned(), due, done: false });
+                    *actionDraft = ("".to_owned(), "".to_owned(), "".to_owned());
+                },
+                Err(e) => actionDraft.2 = e
+            }
+        }
+    });
+
+    ui.small(actionDraft.2.to_owned());
+
+    return *reflection != before;
+}
+
+/// Draws the radar chart or DiSC map for each (name, tally, intensity) series.
+fn show_map_plot(graphType: GraphType, series: &Vec<(String, [f64; 4], [f64; 4])>, colors: &[Color32; 2], ui: &mut Ui) {
+
+    let label_series = series.clone();
+    let label_fmt = move |name: &str, _value: &PlotPoint| {
+
+        match label_series.iter().find(|s| s.0 == name) {
+            Some(s) => format!("{}\nD: {}  i: {}  S: {}  C: {}", s.0, round_to_tenth(s.2[0]), round_to_tenth(s.2[1]), round_to_tenth(s.2[2]), round_to_tenth(s.2[3])),
+            None => String::new()
+        }
+    };
+
+    let ring = |radius: f64| PlotPoints::from_parametric_callback(move |t| (radius * t.cos(), radius * t.sin()), 0.0..=std::f64::consts::TAU, 96);
+
+    Plot::new("Map")
+        .data_aspect(1.0)
+        .view_aspect(1.0)
+        .height(500.0)
+        .show_x(false)
+        .show_y(false)
+        .show_axes([false, false])
+        .allow_drag(false)
+        .allow_zoom(false)
+        .allow_scroll(false)
+        .allow_boxed_zoom(false)
+        .show_background(false)
+        .legend(Legend::default())
+        .label_formatter(label_fmt)
+        .show(ui, |plot_ui| {
+
+            let names = ["D", "i", "S", "C"];
+
+            match graphType {
+                GraphType::Radar => {
+
+                    for seg in 1..=7 {
+                        plot_ui.line(Line::new(ring(seg as f64 * 4.0 / 28.0)).color(Color32::GRAY).width(if seg == 4 { 1.0 } else { 0.5 }));
+                    }
+
+                    for d in 0..4 {
+
+                        let tip = crate::graph::radar_point(d, 28.0);
+                        let label = crate::graph::radar_point(d, 31.0);
+
+                        plot_ui.line(Line::new(PlotPoints::new(vec![[0.0, 0.0], tip])).color(Color32::GRAY).width(1.0));
+                        plot_ui.text(Text::new(PlotPoint::new(label[0], label[1]), RichText::new(names[d]).strong()));
+                    }
+
+                    for (k, s) in series.iter().enumerate() {
+
+                        let points: Vec<[f64; 2]> = (0..4).map(|d| crate::graph::radar_point(d, s.2[d])).collect();
+
+                        plot_ui.polygon(Polygon::new(PlotPoints::new(points.clone())).color(colors[k]).fill_alpha(0.25).name(s.0.to_owned()));
+                        plot_ui.points(Points::new(PlotPoints::new(points)).radius(4.0).color(colors[k]).name(s.0.to_owned()));
+                    }
+                },
+                GraphType::Circle => {
+
+                    plot_ui.line(Line::new(ring(1.0)).color(Color32::GRAY).width(1.5));
+                    plot_ui.line(Line::new(ring(0.5)).color(Color32::GRAY).width(0.5));
+                    plot_ui.line(Line::new(PlotPoints::new(vec![[-1.0, 0.0], [1.0, 0.0]])).color(Color32::GRAY).width(1.0));
+                    plot_ui.line(Line::new(PlotPoints::new(vec![[0.0, -1.0], [0.0, 1.0]])).color(Color32::GRAY).width(1.0));
+
+                    for d in 0..4 {
+
+                        let angle = crate::graph::MAP_ANGLES[d];
+                        plot_ui.text(Text::new(PlotPoint::new(0.75 * angle.cos(), 0.75 * angle.sin()), RichText::new(names[d]).heading().color(Color32::GRAY)));
+                    }
+
+                    for (k, s) in series.iter().enumerate() {
+
+                        let (angle, distance) = crate::graph::map_placement(s.2);
+
+                        plot_ui.points(Points::new(PlotPoints::new(vec![[distance * angle.cos(), distance * angle.sin()]])).radius(7.0).color(colors[k]).name(s.0.to_owned()));
+                    }
+                },
+                // Drawn on the intensity plot.
+                GraphType::Line | GraphType::Bar => ()
+            }
+
+            plot_ui.set_plot_bounds(PlotBounds::from_min_max([-1.25, -1.25], [1.25, 1.25]));
+        });
+}
+
+fn show_profile_section(p: &mut Profile, ui: &mut Ui) {
+
+    ui.group(|ui| {
+
+        ui.add_space(5.0);
+        ui.label(RichText::new(p.name.to_owned() + " Pattern").strong());
+        ui.add_space(15.0);
+
+        ui.horizontal_wrapped(|ui| {
+
+            for pair in &p.aspects {
+
+                ui.small(RichText::new(pair.0.to_owned()).strong().color(Color32::from_rgb(137, 207, 240)));
+                ui.small(RichText::new(pair.1.to_owned()));
+                ui.end_row();
+            }
+        });
+
+        ui.add_space(20.0);
+
+        ui.horizontal_wrapped(|ui| {
+
+            ui.small(RichText::new(p.content.to_owned()));
+            ui.end_row();
+        });
+    });
+}
+
+fn show_profile_editor_page(profiles: &mut Vec<Profile>, profileEditor: &mut ProfileEditor, highlights: &mut Vec<DimensionHighlights>, highlightsPath: &mut String, highlightsStatus: &mut String, ui: &mut Ui) {
+
+    let before = profiles.clone();
+    let mut structural = false;
+    let mut undone = false;
+
+    ui.horizontal(|ui| {
+
+        ui.label("Library");
+        ui.text_edit_singleline(&mut profileEditor.path);
+
+        if ui.button("Open").clicked() {
+            match load_library(std::path::Path::new(profileEditor.path.trim())) {
+                Ok(library) => { *profiles = library; structural = true; profileEditor.status.clear(); },
+                Err(e) => profileEditor.status = e
+            }
+        }
+
+        if ui.button("Save").on_hover_text("Writes every pattern to the library file, which is loaded when the program starts").clicked() {
+            profileEditor.status = match save_library(profiles, std::path::Path::new(profileEditor.path.trim())) {
+                Ok(()) => format!("Saved to {}", profileEditor.path.trim()),
+                Err(e) => e
+            };
+        }
+
+        ui.add_space(20.0);
+
+        if ui.add_enabled(profileEditor.can_undo(), egui::Button::new("Undo")).clicked() {
+            profileEditor.undo(profiles);
+            undone = true;
+        }
+
+        if ui.button("Restore Defaults").on_hover_text("Puts back the built-in wording for every pattern").clicked() {
+            *profiles = default_profiles();
+            structural = true;
+        }
+    });
+
+    ui.small(profileEditor.status.to_owned());
+    ui.add_space(5.0);
+
+    ui.horizontal(|ui| {
+
+        ui.label("Dimension Highlights");
+        ui.text_edit_singleline(highlightsPath);
+
+        if ui.button("Open").clicked() {
+            *highlightsStatus = match load_highlights(std::path::Path::new(highlightsPath.trim())) {
+                Ok(h) => { *highlights = h; format!("Loaded {}", highlightsPath.trim()) },
+                Err(e) => e
+            };
+        }
+
+        if ui.button("Export").on_hover_text("Writes the D, i, S and C descriptions to a file that can be edited or translated, then opened again").clicked() {
+            *highlightsStatus = match save_highlights(highlights, std::path::Path::new(highlightsPath.trim())) {
+                Ok(()) => format!("Saved to {}", highlightsPath.trim()),
+                Err(e) => e
+            };
+        }
+
+        if ui.button("Restore Defaults").clicked() {
+            *highlights = default_highlights();
+            highlightsStatus.clear();
+        }
+    });
+
+    ui.small(highlightsStatus.to_owned());
+    ui.add_space(5.0);
+
+    profileEditor.selected = profileEditor.selected.min(profiles.len().saturating_sub(1));
+    let selected = profileEditor.selected;
+
+    ui.horizontal(|ui| {
+
+        ui.label("Pattern");
+
+        egui::ComboBox::from_id_source("Profile Editor Pattern")
+            .selected_text(profiles[selected].name.to_owned())
+            .show_ui(ui, |ui| {
+                for (k, p) in profiles.iter().enumerate() {
+                    ui.selectable_value(&mut profileEditor.selected, k, &p.name);
+                }
+            });
+    });
+
+    ui.separator();
+
+    let p = &mut profiles[selected];
+
+    ui.columns(2, |columns| {
+
+        egui::ScrollArea::vertical().id_source("Profile Editor Fields").show(&mut columns[0], |ui| {
+
+            ui.label(RichText::new("Name").color(Color32::from_rgb(137, 207, 240)));
+            ui.text_edit_singleline(&mut p.name);
+
+            ui.add_space(10.0);
+            ui.label(RichText::new("Aspects").color(Color32::from_rgb(137, 207, 240)));
+
+            let count = p.aspects.len();
+            let mut moved: Option<(usize, usize)> = None;
+            let mut removed: Option<usize> = None;
+
+            for (k, aspect) in p.aspects.iter_mut().enumerate() {
+
+                ui.horizontal(|ui| {
+                    ui.add(egui::TextEdit::singleline(&mut aspect.0).desired_width(160.0));
+                    if ui.add_enabled(k > 0, egui::Button::new("⏶").small()).clicked() { moved = Some((k, k - 1)); }
+                    if ui.add_enabled(k + 1 < count, egui::Button::new("⏷").small()).clicked() { moved = Some((k, k + 1)); }
+                    if ui.small_button("✖").on_hover_text("Remove aspect").clicked() { removed = Some(k); }
+                });
+
+                ui.add(egui::TextEdit::multiline(&mut aspect.1).desired_rows(2).desired_width(f32::INFINITY));
+                ui.add_space(5.0);
+            }
+
+            if let Some((from, to)) = moved { p.aspects.swap(from, to); }
+            if let Some(k) = removed { p.aspects.remove(k); }
+
+            if ui.small_button("Add Aspect").clicked() { p.aspects.push(("".to_owned(), "".to_owned())); }
+
+            ui.add_space(10.0);
+            ui.label(RichText::new("Content").color(Color32::from_rgb(137, 207, 240)));
+            ui.add(egui::TextEdit::multiline(&mut p.content).desired_rows(10).desired_width(f32::INFINITY));
+
+            ui.add_space(20.0);
+        });
+
+        egui::ScrollArea::vertical().id_source("Profile Editor Preview").show(&mut columns[1], |ui| {
+            show_profile_section(&mut p.clone(), ui);
+        });
+    });
+
+    // Undoing is not an edit of its own; recording it would push back the state just undone.
+    if *profiles != before && !undone {
+
+        let field = if structural { None } else { edited_field(&before[selected], &profiles[selected]).map(|f| (selected, f)) };
+        profileEditor.record(before, field);
+    }
+}
+
+fn show_settings_page(fontSizes: &mut (f32, f32, f32, f32, f32), ui: &mut Ui) {
+
+    ui.add(egui::Slider::new(&mut fontSizes.0, 8.0..=32.0).text("Heading"));
+    ui.add(egui::Slider::new(&mut fontSizes.1, 8.0..=32.0).text("Body"));
+    //ui.add(egui::Slider::new(&mut fontSizes.2, 8.0..=32.0).text("Monospace"));
+    ui.add(egui::Slider::new(&mut fontSizes.3, 8.0..=32.0).text("Button"));
+    ui.add(egui::Slider::new(&mut fontSizes.4, 8.0..=32.0).text("Small"));
+}
+
+/// The facilitator passphrase prompt shown in place of anything that needs admin mode.
+fn show_admin_unlock(admin: &AdminConfig, adminUnlocked: &mut bool, passphraseInput: &mut String, adminStatus: &mut String, ui: &mut Ui) {
+
+    ui.label("Enter the facilitator passphrase to unlock admin mode.");
+
+    ui.horizontal(|ui| {
+
+        let field = ui.add(egui::TextEdit::singleline(passphraseInput).password(true));
+
+        if ui.button("Unlock").clicked() || (field.lost_focus() && ui.input(|i| i.key_pressed(egui::Key::Enter))) {
+
+            if admin.verify(passphraseInput) {
+                *adminUnlocked = true;
+                adminStatus.clear();
+            } else {
+                *adminStatus = "Incorrect passphrase".to_owned();
+            }
+
+            passphraseInput.clear();
+        }
+    });
+
+    ui.small(adminStatus.to_owned());
+}
+
+fn show_admin_page(admin: &mut AdminConfig, adminUnlocked: &mut bool, passphraseInput: &mut String, adminStatus: &mut String, releasedResponses: &mut Vec<(Choice, Choice)>, confirmClear: &mut bool, questions: &mut Vec<Vec<(String, Choice, Choice)>>, responses: &mut Vec<(Choice, Choice)>, responseStarted: &mut Option<DateTime<Utc>>, responseEvents: &mut Vec<ResponseEvent>, responseShuffle: &mut Option<Shuffle>, forms: &mut Vec<Form>, formPath: &mut String, formStatus: &mut String, paper: (&mut String, &mut String), sessions: &mut Vec<Session>, profiles: &mut Vec<Profile>, dataset: (&mut String, &mut Coarsen, &mut String, &mut String), batch: (&mut String, &mut String, &mut String), highlights: &Vec<DimensionHighlights>, graphType: GraphType, sealedSessions: &mut Option<Sealed>, vaultKey: &mut Option<VaultKey>, vaultPassphrase: &mut String, vaultStatus: &mut String, ui: &mut Ui) {
+
+    if admin.is_protected() && !*adminUnlocked {
+        show_admin_unlock(admin, adminUnlocked, passphraseInput, adminStatus, ui);
+        return;
+    }
+
+    egui::ScrollArea::vertical().show(ui, |ui| {
+
+        ui.label(RichText::new("Passphrase").strong().color(Color32::from_rgb(137, 207, 240)));
+
+        if !admin.is_protected() {
+            ui.small("No passphrase is set, so participants can see and change everything.");
+        }
+
+        ui.horizontal(|ui| {
+
+            ui.add(egui::TextEdit::singleline(passphraseInput).password(true).hint_text("New passphrase"));
+
+            if ui.button(if admin.is_protected() { "Change Passphrase" } else { "Set Passphrase" }).clicked() {
+
+                *adminStatus = match admin.set_passphrase(passphraseInput) {
+                    Ok(()) => { *adminUnlocked = true; "Passphrase saved".to_owned() },
+                    Err(e) => e
+                };
+
+                passphraseInput.clear();
+            }
+
+            if admin.is_protected() && ui.button("Lock").on_hover_text("Return to participant mode").clicked() {
+                *adminUnlocked = false;
+                adminStatus.clear();
+            }
+        });
+
+        ui.small(adminStatus.to_owned());
+
+        ui.add_space(10.0);
+        ui.separator();
+
+        ui.label(RichText::new("Participant Results").strong().color(Color32::from_rgb(137, 207, 240)));
+
+        ui.checkbox(&mut admin.hide_until_reviewed, "Hide results until a facilitator has reviewed them");
+
+        if admin.hide_until_reviewed {
+
+            ui.horizontal(|ui| {
+
+                if ui.add_enabled(*releasedResponses != *responses, egui::Button::new("Release Current Results")).clicked() {
+                    *releasedResponses = responses.clone();
+                }
+
+                ui.small(if *releasedResponses == *responses { "The participant can see their current results." } else { "The current results are hidden from the participant." });
+            });
+        }
+
+        ui.add_space(5.0);
+        ui.label("Sections participants may see:");
+        ui.checkbox(&mut admin.visible.intensities, "Intensity and segment numbers");
+        ui.checkbox(&mut admin.visible.highlights, "Dimension highlights");
+        ui.checkbox(&mut admin.visible.graph, "Graph");
+        ui.checkbox(&mut admin.visible.profile, "Profile Pattern");
+
+        ui.add_space(10.0);
+        ui.separator();
+
+        ui.label(RichText::new("Instrument").strong().color(Color32::from_rgb(137, 207, 240)));
+
+        let fingerprint = instrument_fingerprint(questions);
+        ui.label(format!("Version {} ({} groups)", fingerprint, questions.len()));
+
+        match admin.locked_instrument.to_owned() {
+            Some(locked) => {
+
+                if locked != fingerprint {
+                    ui.colored_label(Color32::from_rgb(240, 170, 137), format!("Locked to version {}, which is not the instrument in use.", locked));
+                }
+
+                if ui.button("Unlock Instrument").clicked() { admin.locked_instrument = None; }
+            },
+            None => {
+                if ui.button("Lock Instrument").on_hover_text("Prevents the instrument from being changed").clicked() { admin.locked_instrument = Some(fingerprint); }
+            }
+        }
+
+        ui.add_space(5.0);
+
+        let mut choice = form_label(&admin.form);
+
+        ui.horizontal(|ui| {
+
+            ui.label("Form");
+
+            egui::ComboBox::from_id_source("Form")
+                .selected_text(choice.to_owned())
+                .show_ui(ui, |ui| {
+                    ui.selectable_value(&mut choice, STANDARD_FORM.to_owned(), STANDARD_FORM);
+                    for form in forms.iter() {
+                        ui.selectable_value(&mut choice, form.name.to_owned(), &form.name);
+                    }
+                });
+        });
+
+        if choice != form_label(&admin.form) {
+
+            let form = forms.iter().find(|f| f.name == choice).cloned().unwrap_or(standard_form());
+
+            if admin.allows_instrument(&form.questions) {
+
+                use_form(&form, questions, responses);
+                *responseStarted = None;
+                *responseShuffle = None;
+                responseEvents.clear();
+
+                admin.form = if form.name == STANDARD_FORM { "".to_owned() } else { form.name };
+                formStatus.clear();
+            } else {
+                *formStatus = format!("{} is not the locked instrument version", choice);
+            }
+        }
+
+        ui.horizontal(|ui| {
+
+            ui.label("Load Form");
+            ui.text_edit_singleline(formPath);
+
+            if ui.button("Load").on_hover_text("Adds an instrument file to the forms to choose from").clicked() {
+
+                *formStatus = match load_form(std::path::Path::new(formPath.trim())) {
+                    Ok(form) if !admin.allows_instrument(&form.questions) => format!("{} is not the locked instrument version", form.name),
+                    Ok(form) => {
+                        let name = form.name.to_owned();
+                        forms.retain(|f| f.name != name);
+                        forms.push(form);
+                        format!("Loaded {}", name)
+                    },
+                    Err(e) => e
+                };
+            }
+        });
+
+        #[cfg(not(target_arch = "wasm32"))]
+        ui.horizontal(|ui| {
+
+            let (paperSheetPath, paperKeyPath) = paper;
+
+            ui.label("Answer Sheet");
+            ui.text_edit_singleline(paperSheetPath);
+
+            ui.label("Scoring Key");
+            ui.text_edit_singleline(paperKeyPath);
+
+            if ui.button("Write Paper Form").on_hover_text("Printable pages of the selected form, for sites that answer on paper").clicked() {
+
+                let (sheet, key) = (std::path::Path::new(paperSheetPath.trim()), std::path::Path::new(paperKeyPath.trim()));
+
+                *formStatus = match write_paper_forms(&form_label(&admin.form), questions, sheet, key) {
+                    Ok(()) => format!("Wrote {} and {}", sheet.display(), key.display()),
+                    Err(e) => e
+                };
+            }
+        });
+
+        ui.small(formStatus.to_owned());
+
+        ui.add_space(5.0);
+        ui.checkbox(&mut admin.shuffle_groups, "Shuffle the order of groups for each answer sheet");
+        ui.checkbox(&mut admin.shuffle_words, "Shuffle the order of words within each group");
+
+        ui.add_space(10.0);
+        ui.separator();
+
+        ui.label(RichText::new("Sessions").strong().color(Color32::from_rgb(137, 207, 240)));
+        ui.label(format!("{} saved", sessions.len()));
+
+        for (k, session) in sessions.iter().enumerate() {
+
+            egui::CollapsingHeader::new(&session.name).id_source(("Session Timeline", k)).show(ui, |ui| {
+                show_timeline(session.started, &session.events, session.responses.len(), ui);
+            });
+        }
+
+        ui.horizontal(|ui| {
+
+            if !*confirmClear {
+                if ui.add_enabled(!sessions.is_empty() || sealedSessions.is_some(), egui::Button::new("Securely Wipe Sessions")).clicked() { *confirmClear = true; }
+            } else {
+
+                ui.label("Overwrite and delete every saved session, including encrypted ones?");
+                if ui.button("Wipe").clicked() { wipe_sessions(sessions, sealedSessions, vaultKey); *confirmClear = false; }
+                if ui.button("Cancel").clicked() { *confirmClear = false; }
+            }
+        });
+
+        // The web build has nowhere to write files.
+        #[cfg(not(target_arch = "wasm32"))]
+        show_dataset_export(dataset, sessions, profiles, questions, &admin.form, ui);
+
+        #[cfg(target_arch = "wasm32")]
+        let _ = (dataset, batch, paper, highlights, graphType);
+
+        #[cfg(not(target_arch = "wasm32"))]
+        show_batch_scoring(batch, questions, profiles, highlights, graphType, ui);
+
+        ui.add_space(10.0);
+        ui.separator();
+
+        ui.label(RichText::new("Encrypted Storage").strong().color(Color32::from_rgb(137, 207, 240)));
+
+        match vaultKey {
+            None => {
+
+                ui.small("Saved sessions are stored unencrypted. Encrypting asks for the passphrase every time the program starts; it cannot be recovered if forgotten.");
+
+                ui.horizontal(|ui| {
+
+                    ui.add(egui::TextEdit::singleline(vaultPassphrase).password(true).hint_text("Storage passphrase"));
+
+                    if ui.button("Encrypt").clicked() {
+
+                        *vaultStatus = match VaultKey::create(vaultPassphrase).and_then(|key| { *sealedSessions = Some(key.seal(sessions)?); *vaultKey = Some(key); Ok(()) }) {
+                            Ok(()) => "Saved sessions are now encrypted".to_owned(),
+                            Err(e) => e
+                        };
+
+                        vaultPassphrase.zeroize();
+                    }
+                });
+            },
+            Some(_) => {
+
+                ui.small("Saved sessions are encrypted.");
+
+                ui.horizontal(|ui| {
+
+                    if ui.button("Lock Now").on_hover_text("Removes the sessions from memory until the passphrase is entered again").clicked() {
+
+                        match vaultKey.as_ref().map(|key| key.seal(sessions)) {
+                            Some(Ok(sealed)) => {
+                                *sealedSessions = Some(sealed);
+                                zeroize_sessions(sessions);
+                                *vaultKey = None;
+                                vaultStatus.clear();
+                            },
+                            Some(Err(e)) => *vaultStatus = e,
+                            None => ()
+                        }
+                    }
+
+                    if ui.button("Stop Encrypting").clicked() {
+                        *sealedSessions = None;
+                        *vaultKey = None;
+                        *vaultStatus = "Saved sessions will be stored unencrypted".to_owned();
+                    }
+                });
+            }
+        }
+
+        ui.small(vaultStatus.to_owned());
+    });
+}
+
+/// Asks what setting to keep in mind, as the instructions assume one has been chosen.
+fn show_focus_step(focus: &mut String, focusConfirmed: &mut bool, ui: &mut Ui) {
+
+    ui.label(RichText::new("Setting or Focus").strong().color(Color32::from_rgb(137, 207, 240)));
+    ui.add_space(5.0);
+    ui.label("Before you begin, choose the setting you will think about while answering. Your results describe you in this setting.");
+    ui.add_space(10.0);
+
+    ui.horizontal_wrapped(|ui| {
+        for preset in FOCUS_PRESETS {
+            if ui.selectable_label(focus == preset, preset).clicked() { *focus = preset.to_owned(); }
+        }
+    });
+
+    ui.add_space(5.0);
+
+    ui.horizontal(|ui| {
+        ui.label("Or type your own");
+        ui.add(egui::TextEdit::singleline(focus).hint_text("e.g. Team X"));
+    });
+
+    ui.add_space(10.0);
+
+    if ui.add_enabled(!focus.trim().is_empty(), egui::Button::new("Begin")).clicked() {
+        *focus = focus.trim().to_owned();
+        *focusConfirmed = true;
+    }
+}
+
+/// Writes the sessions answered on the current form without names or free text, for research partners.
+#[cfg(not(target_arch = "wasm32"))]
+fn show_dataset_export(dataset: (&mut String, &mut Coarsen, &mut String, &mut String), sessions: &Vec<Session>, profiles: &mut Vec<Profile>, questions: &Vec<Vec<(String, Choice, Choice)>>, form: &String, ui: &mut Ui) {
+
+    let (datasetPath, datasetCoarsen, datasetSalt, datasetStatus) = dataset;
+
+    ui.add_space(10.0);
+    ui.label(RichText::new("Research Export").strong().color(Color32::from_rgb(137, 207, 240)));
+    ui.small("Names, reflections and typed-in settings are left out. People are identified by a salted hash of their name.");
+
+    ui.horizontal(|ui| {
+
+        ui.label("File");
+        ui.text_edit_singleline(datasetPath).on_hover_text("Ending the name in .json writes JSON; anything else writes CSV with a separate data dictionary");
+
+        ui.label("Dates");
+
+        egui::ComboBox::from_id_source("Dataset Dates")
+            .selected_text(format!("{:?}", datasetCoarsen))
+            .show_ui(ui, |ui| {
+                ui.selectable_value(datasetCoarsen, Coarsen::Exact, "Exact");
+                ui.selectable_value(datasetCoarsen, Coarsen::Day, "Day");
+                ui.selectable_value(datasetCoarsen, Coarsen::Month, "Month");
+                ui.selectable_value(datasetCoarsen, Coarsen::Omit, "Omit");
+            });
+    });
+
+    ui.horizontal(|ui| {
+
+        ui.label("Salt");
+        ui.add(egui::TextEdit::singleline(datasetSalt).password(true).hint_text("random"))
+            .on_hover_text("Use the same secret salt to link people across exports; leave blank so no two exports can be linked");
+
+        // Hashes of likely names under a short salt can simply be tried one by one.
+        if !datasetSalt.is_empty() && datasetSalt.len() < MIN_SALT_BYTES {
+            ui.colored_label(Color32::from_rgb(240, 170, 137), format!("Short salts can be guessed; use at least {} characters", MIN_SALT_BYTES));
+        }
+
+        let onForm: Vec<&Session> = sessions.iter().filter(|s| form_label(&s.form) == form_label(form) && s.responses.len() == questions.len()).collect();
+
+        if ui.add_enabled(!onForm.is_empty(), egui::Button::new("Export Anonymized Data")).clicked() {
+
+            let records: Vec<DatasetRecord<'_>> = onForm.iter().map(|s| {
+
+                let seg = (intensity_to_segment(s.intensity.0), intensity_to_segment(s.intensity.1), intensity_to_segment(s.intensity.2), intensity_to_segment(s.intensity.3));
+
+                DatasetRecord {
+                    name: &s.name,
+                    date: s.date(),
+                    focus: &s.focus,
+                    form: &s.form,
+                    seconds: completion_time(s.started, &s.events, s.responses.len()),
+                    responses: &s.responses,
+                    events: &s.events,
+                    tally: s.tally,
+                    intensity: s.intensity,
+                    pattern: profilePatternLookup(profiles, seg).name
+                }
+            }).collect();
+
+            let salt = if datasetSalt.is_empty() { random_salt() } else { Ok(datasetSalt.as_bytes().to_vec()) };
+
+            *datasetStatus = match salt.and_then(|salt| write_dataset(std::path::Path::new(datasetPath.trim()), questions, &records, &salt, *datasetCoarsen)) {
+                Ok(()) if onForm.len() < sessions.len() => format!("Exported {} sessions; {} answered on other forms were left out", onForm.len(), sessions.len() - onForm.len()),
+                Ok(()) => format!("Exported {} sessions", onForm.len()),
+                Err(e) => e
+            };
+        }
+    });
+
+    ui.small(datasetStatus.to_owned());
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+fn show_batch_scoring(batch: (&mut String, &mut String, &mut String), questions: &Vec<Vec<(String, Choice, Choice)>>, profiles: &mut Vec<Profile>, highlights: &Vec<DimensionHighlights>, graphType: GraphType, ui: &mut Ui) {
+
+    let (batchInput, batchOutput, batchStatus) = batch;
+
+    ui.add_space(10.0);
+    ui.label(RichText::new("Batch Scoring").strong().color(Color32::from_rgb(137, 207, 240)));
+    ui.small("Scores every answer file in a folder against the loaded form, writing summary.csv, errors.log and a report per person.");
+
+    ui.horizontal(|ui| {
+
+        ui.label("Answers");
+        ui.text_edit_singleline(batchInput).on_hover_text("Folder of answer files, one person per file");
+
+        ui.label("Output");
+        ui.text_edit_singleline(batchOutput);
+
+        if ui.button("Score Folder").clicked() {
+
+            let input = std::path::Path::new(batchInput.trim());
+            let out = std::path::Path::new(batchOutput.trim());
+
+            *batchStatus = match score_directory(input, out, questions, profiles, highlights, graphType) {
+                Ok(summary) if summary.failed.is_empty() => format!("Scored {} files into {}", summary.scored, out.display()),
+                Ok(summary) => format!("Scored {} files into {}; {} could not be scored, see errors.log", summary.scored, out.display(), summary.failed.len()),
+                Err(e) => e
+            };
+        }
+    });
+
+    ui.small(batchStatus.to_owned());
+}
+
+/// Replaces the instrument and clears any answers to the old one.
+fn use_form(form: &Form, questions: &mut Vec<Vec<(String, Choice, Choice)>>, responses: &mut Vec<(Choice, Choice)>) {
+
+    *questions = form.questions.clone();
+    *responses = vec![(Choice::NONE, Choice::NONE); questions.len()];
+}
+
+fn form_label(form: &str) -> String {
+
+    return if form.is_empty() { STANDARD_FORM.to_owned() } else { form.to_owned() };
+}
+
+fn focus_name(focus: &str, empty: &str) -> String {
+
+    return if focus.is_empty() { empty.to_owned() } else { focus.to_owned() };
+}
+
+/// Builds and checks alternate instrument forms, with a preview of the answer sheet they make.
+fn show_editor_page(editorForm: &mut Form, editorPath: &mut String, editorStatus: &mut String, editorPreview: &mut Option<Vec<(Choice, Choice)>>, forms: &mut Vec<Form>, activeForm: &String, ui: &mut Ui) {
+
+    ui.horizontal(|ui| {
+
+        if ui.button("New").on_hover_text("Starts an empty form").clicked() {
+            *editorForm = Form { name: "".to_owned(), questions: vec![blank_group()] };
+            editorStatus.clear();
+        }
+
+        if ui.button("Copy Standard").on_hover_text("Starts from the built-in words").clicked() {
+            *editorForm = Form { name: "".to_owned(), questions: standard_form().questions };
+            editorStatus.clear();
+        }
+
+        ui.add_space(20.0);
+        ui.label("File");
+        ui.text_edit_singleline(editorPath);
+
+        if ui.button("Open").clicked() {
+            match read_form(std::path::Path::new(editorPath.trim())) {
+                Ok(form) => { *editorForm = form; editorStatus.clear(); },
+                Err(e) => *editorStatus = e
+            }
+        }
+
+        let problems = validate(editorForm);
+
+        if ui.add_enabled(problems.is_empty(), egui::Button::new("Save")).on_hover_text("Writes the instrument file and adds it to the forms on the Admin page").clicked() {
+
+            let path = std::path::Path::new(editorPath.trim());
+            let mut form = editorForm.clone();
+            form.name = form.name.trim().to_owned();
+
+            *editorStatus = match save_form(&form, path) {
+                Err(e) => e,
+                // Sessions in progress were scored against the form as it was loaded.
+                Ok(()) if form.name == *activeForm => format!("Saved to {}; {} is in use, so load the file again from the Admin page to switch to the new words", path.display(), form.name),
+                Ok(()) => {
+                    forms.retain(|f| f.name != form.name);
+                    forms.push(form);
+                    format!("Saved to {}", path.display())
+                }
+            };
+        }
+    });
+
+    ui.small(editorStatus.to_owned());
+    ui.add_space(5.0);
+
+    ui.horizontal(|ui| {
+        ui.label("Name");
+        ui.text_edit_singleline(&mut editorForm.name);
+    });
+
+    ui.add_space(5.0);
+
+    let problems = validate(editorForm);
+
+    if problems.is_empty() {
+        ui.label(RichText::new("✔ Every group is balanced").color(Color32::from_rgb(110, 190, 110)));
+    } else {
+        egui::CollapsingHeader::new(RichText::new(format!("⚠ {} problems", problems.len())).color(Color32::from_rgb(240, 200, 80))).show(ui, |ui| {
+            for problem in &problems { ui.label(problem); }
+        });
+    }
+
+    ui.add_space(5.0);
+
+    let previewing = editorPreview.is_some();
+
+    if ui.selectable_label(previewing, "Preview Response Page").clicked() {
+        *editorPreview = if previewing { None } else { Some(vec![(Choice::NONE, Choice::NONE); editorForm.questions.len()]) };
+    }
+
+    ui.separator();
+
+    if let Some(responses) = editorPreview {
+
+        responses.resize(editorForm.questions.len(), (Choice::NONE, Choice::NONE));
+        show_form_preview(&editorForm.questions, responses, ui);
+        return;
+    }
+
+    egui::ScrollArea::vertical().show(ui, |ui| {
+
+        // (group, move to, remove) from the buttons this frame, applied after drawing.
+        let mut moved: Option<(usize, usize)> = None;
+        let mut removed: Option<usize> = None;
+
+        let count = editorForm.questions.len();
+
+        for (g, group) in editorForm.questions.iter_mut().enumerate() {
+
+            ui.horizontal(|ui| {
+                ui.label(RichText::new(format!("Group {}", g + 1)).strong().color(Color32::from_rgb(137, 207, 240)));
+                if ui.add_enabled(g > 0, egui::Button::new("⏶").small()).clicked() { moved = Some((g, g - 1)); }
+                if ui.add_enabled(g + 1 < count, egui::Button::new("⏷").small()).clicked() { moved = Some((g, g + 1)); }
+                if ui.small_button("Remove Group").clicked() { removed = Some(g); }
+            });
+
+            let mut removedWord: Option<usize> = None;
+
+            egui::Grid::new(format!("Editor Group {}", g)).spacing(Vec2 {x: 10.0, y: 4.0}).show(ui, |ui| {
+
+                ui.label("");
+                ui.label("MOST");
+                ui.label("LEAST");
+                ui.end_row();
+
+                for (w, q) in group.iter_mut().enumerate() {
+
+                    ui.text_edit_singleline(&mut q.0);
+                    scale_picker(format!("Most {} {}", g, w), &mut q.1, ui);
+                    scale_picker(format!("Least {} {}", g, w), &mut q.2, ui);
+
+                    if ui.small_button("✖").on_hover_text("Remove word").clicked() { removedWord = Some(w); }
+                    ui.end_row();
+                }
+            });
+
+            if let Some(w) = removedWord { group.remove(w); }
+
+            if ui.small_button("Add Word").clicked() {
+                let (most, least) = (free_key(group.iter().map(|q| q.1).collect()), free_key(group.iter().map(|q| q.2).collect()));
+                group.push(("".to_owned(), most, least));
+            }
+
+            ui.add_space(10.0);
+        }
+
+        if let Some((from, to)) = moved { editorForm.questions.swap(from, to); }
+        if let Some(g) = removed { editorForm.questions.remove(g); }
+
+        if ui.button("Add Group").clicked() { editorForm.questions.push(blank_group()); }
+
+        ui.add_space(20.0);
+    });
+}
+
+fn scale_picker(id: String, choice: &mut Choice, ui: &mut Ui) {
+
+    let label = |c: Choice| match c {
+        Choice::E => "E (non-scoring)".to_owned(),
+        Choice::NONE => "—".to_owned(),
+        _ => scale_name(c).to_owned()
+    };
+
+    egui::ComboBox::from_id_source(id)
+        .selected_text(label(*choice))
+        .width(120.0)
+        .show_ui(ui, |ui| {
+            for scale in SCALES {
+                ui.selectable_value(choice, scale, label(scale));
+            }
+        });
+}
+
+/// A new group of four words keyed D, i, S and C in both columns.
+fn blank_group() -> Vec<(String, Choice, Choice)> {
+
+    return [Choice::A, Choice::B, Choice::C, Choice::D].iter().map(|k| ("".to_owned(), *k, *k)).collect();
+}
+
+/// The first key the column doesn't use yet, so a new word keeps the group balanced while it can.
+fn free_key(used: Vec<Choice>) -> Choice {
+
+    return SCALES.iter().copied().find(|k| !used.contains(k)).unwrap_or(Choice::E);
+}
+
+/// The draft's answer sheet as the Response page would show it.
+fn show_form_preview(questions: &Vec<Vec<(String, Choice, Choice)>>, responses: &mut Vec<(Choice, Choice)>, ui: &mut Ui) {
+
+    egui::ScrollArea::vertical().show(ui, |ui| {
+
+        egui::Grid::new("Editor Preview")
+        .striped(true)
+        .spacing(Vec2 {x: 10.0, y: 0.0})
+        .min_row_height(4.0)
+        .show(ui, |ui|{
+
+            ui.label("");
+            ui.add_space(10.0);
+            ui.label("");
+            ui.add_space(10.0);
+            ui.label(RichText::new("MOST").color(Color32::from_rgb(137, 207, 240)));
+            ui.label(RichText::new("LEAST").color(Color32::from_rgb(137, 207, 240)));
+            ui.end_row();
+
+            for i in 0..questions.len() {
+
+                ui.label(RichText::new((i + 1).to_string()).strong().color(Color32::from_rgb(137, 207, 240)));
+
+                for q in &questions[i] {
+
+                    ui.end_row();
+                    ui.add_space(10.0);
+                    ui.label(q.0.to_owned());
+                    ui.add_space(10.0);
+                    ui.radio_value(&mut responses[i].0, q.1, "");
+                    ui.radio_value(&mut responses[i].1, q.2, "");
+                    ui.end_row();
+                }
+
+                ui.label("");
+                ui.end_row();
+            }
+        });
+
+        ui.add_space(20.0);
+    });
+}
+
+fn show_statistics_page(sessions: &Vec<Session>, profiles: &mut Vec<Profile>, questions: &Vec<Vec<(String, Choice, Choice)>>, form: &String, ui: &mut Ui) {
+
+    let results: Vec<((i8, i8, i8, i8), (i8, i8, i8, i8), String)> = sessions.iter().map(|s| {
+
+        let seg = (intensity_to_segment(s.intensity.0), intensity_to_segment(s.intensity.1), intensity_to_segment(s.intensity.2), intensity_to_segment(s.intensity.3));
+        (s.tally, s.intensity, profilePatternLookup(profiles, seg).name)
+    }).collect();
+
+    let norms = match norms(&results) {
+        Some(n) => n,
+        None => { ui.label("No sessions have been saved yet."); return; }
+    };
+
+    ui.label(format!("Norm group: {} saved sessions", results.len()));
+
+    if results.len() < NORM_MIN_RESULTS {
+        ui.small(format!("Percentiles appear on the Results page once {} sessions are saved.", NORM_MIN_RESULTS));
+    }
+
+    egui::ScrollArea::vertical().show(ui, |ui| {
+
+        for (title, rows) in [("Tally", &norms.tally), ("Intensity", &norms.intensity)] {
+
+            ui.add_space(10.0);
+            ui.label(RichText::new(title).strong().color(Color32::from_rgb(137, 207, 240)));
+
+            show_describe_table(title, rows, ui);
+        }
+
+        ui.add_space(10.0);
+        ui.label(RichText::new("Patterns").strong().color(Color32::from_rgb(137, 207, 240)));
+
+        egui::Grid::new("Pattern Frequency").num_columns(3).striped(true).min_col_width(ui.available_width() / 6.0).show(ui, |ui| {
+
+            for (name, count) in &norms.patterns {
+                ui.label(name);
+                ui.label(count.to_string());
+                ui.label(format!("{:.0}%", 100.0 * *count as f64 / results.len() as f64));
+                ui.end_row();
+            }
+        });
+
+        ui.add_space(10.0);
+        ui.label(RichText::new(format!("Reliability of the {} Form", form_label(form))).strong().color(Color32::from_rgb(137, 207, 240)));
+
+        show_reliability(sessions, questions, form, ui);
+
+        ui.add_space(20.0);
+    });
+}
+
+/// Internal consistency of each scale and how each word performs, over the sessions answered on the current form.
+fn show_reliability(sessions: &Vec<Session>, questions: &Vec<Vec<(String, Choice, Choice)>>, form: &String, ui: &mut Ui) {
+
+    let records: Vec<ItemRecord<'_>> = sessions.iter()
+        .filter(|s| form_label(&s.form) == form_label(form) && s.responses.len() == questions.len())
+        .map(|s| ItemRecord { responses: &s.responses, events: &s.events })
+        .collect();
+
+    let analysis = match analyze(questions, &records) {
+        Some(a) => a,
+        None => { ui.small(format!("Needs at least {} sessions answered on this form; {} so far.", MIN_RESPONDENTS, records.len())); return; }
+    };
+
+    ui.small(format!("{} respondents. Forced-choice answers keep alpha and correlations lower than on rating-scale tests, so compare forms with each other.", analysis.respondents));
+    ui.add_space(5.0);
+
+    egui::Grid::new("Alpha").num_columns(5).striped(true).min_col_width(ui.available_width() / 8.0).show(ui, |ui| {
+
+        ui.label("");
+        for name in ["D", "i", "S", "C"] { ui.label(RichText::new(name).strong()); }
+        ui.end_row();
+
+        ui.label("Cronbach's alpha");
+        for alpha in analysis.alpha {
+            ui.label(alpha.map(|a| format!("{:.2}", a)).unwrap_or("—".to_owned()));
+        }
+        ui.end_row();
+    });
+
+    ui.add_space(10.0);
+
+    egui::Grid::new("Item Statistics").num_columns(5).striped(true).min_col_width(ui.available_width() / 8.0).show(ui, |ui| {
+
+        for heading in ["Group", "Word", "MOST", "LEAST", "Item-total r"] {
+            ui.label(RichText::new(heading).strong());
+        }
+        ui.end_row();
+
+        for item in &analysis.items {
+
+            ui.label((item.group + 1).to_string());
+            ui.label(&item.word);
+            ui.label(format!("{:.0}%", 100.0 * item.most_rate));
+            ui.label(format!("{:.0}%", 100.0 * item.least_rate));
+
+            match item.item_total {
+                Some(r) if r < WEAK_CORRELATION => { ui.label(RichText::new(format!("{:.2} weak", r)).color(Color32::from_rgb(240, 200, 80))); },
+                Some(r) => { ui.label(format!("{:.2}", r)); },
+                None => { ui.label("—"); }
+            }
+
+            ui.end_row();
+        }
+    });
+}
+
+fn show_describe_table(id: &str, rows: &[Describe; 4], ui: &mut Ui) {
+
+    egui::Grid::new(format!("Statistics {}", id)).num_columns(6).striped(true).min_col_width(ui.available_width() / 8.0).show(ui, |ui| {
+
+        for heading in ["", "Mean", "SD", "Min", "Median", "Max"] {
+            ui.label(RichText::new(heading).strong());
+        }
+        ui.end_row();
+
+        for (name, d) in ["D", "i", "S", "C"].iter().zip(rows) {
+
+            ui.label(RichText::new(*name).strong());
+            ui.label(format!("{:.1}", d.mean));
+            ui.label(format!("{:.1}", d.sd));
+            ui.label(format!("{:.0}", d.min));
+            ui.label(format!("{:.1}", d.median));
+            ui.label(format!("{:.0}", d.max));
+            ui.end_row();
+        }
+    });
+}
+
+/// One person's sessions over time: intensity per dimension, segment changes and pattern changes.
+fn show_history_page(historyPerson: &mut String, historyFocus: &mut String, sessions: &Vec<Session>, profiles: &mut Vec<Profile>, ui: &mut Ui) {
+
+    let mut people: Vec<String> = sessions.iter().map(|s| s.name.to_owned()).collect();
+    people.sort_by_key(|p| p.to_lowercase());
+    people.dedup();
+
+    if people.is_empty() {
+        ui.label("No sessions have been saved yet.");
+        return;
+    }
+
+    if !people.contains(historyPerson) { *historyPerson = people[0].to_owned(); }
+
+    let mut focuses: Vec<String> = sessions.iter().filter(|s| s.name == *historyPerson).map(|s| s.focus.to_owned()).collect();
+    focuses.sort_by_key(|f| f.to_lowercase());
+    focuses.dedup();
+
+    if !focuses.contains(historyFocus) { historyFocus.clear(); }
+
+    ui.horizontal(|ui| {
+
+        ui.label("Person");
+
+        egui::ComboBox::from_id_source("History Person")
+            .selected_text(historyPerson.to_owned())
+            .show_ui(ui, |ui| {
+                for person in &people {
+                    ui.selectable_value(historyPerson, person.to_owned(), person);
+                }
+            });
+
+        ui.add_space(20.0);
+        ui.label("Focus");
+
+        egui::ComboBox::from_id_source("History Focus")
+            .selected_text(focus_name(historyFocus, "Every focus"))
+            .show_ui(ui, |ui| {
+                ui.selectable_value(historyFocus, "".to_owned(), "Every focus");
+                for f in focuses.iter().filter(|f| !f.is_empty()) {
+                    ui.selectable_value(historyFocus, f.to_owned(), f);
+                }
+            });
+    });
+
+    // The latest result for each focus, side by side.
+    if focuses.len() > 1 {
+
+        ui.add_space(10.0);
+
+        egui::Grid::new("Focus Columns").num_columns(focuses.len() + 1).striped(true).min_col_width(ui.available_width() / (focuses.len() + 2) as f32).show(ui, |ui| {
+
+            let latest: Vec<&Session> = focuses.iter()
+                .filter_map(|f| sessions.iter().filter(|s| s.name == *historyPerson && s.focus == *f).max_by_key(|s| s.date()))
+                .collect();
+
+            ui.label("");
+            for s in &latest { ui.label(RichText::new(focus_name(&s.focus, "No focus")).strong().color(Color32::from_rgb(137, 207, 240))); }
+            ui.end_row();
+
+            for (d, name) in ["D", "i", "S", "C"].iter().enumerate() {
+                ui.label(RichText::new(*name).strong());
+                for s in &latest {
+                    let v = [s.intensity.0, s.intensity.1, s.intensity.2, s.intensity.3][d];
+                    ui.label(format!("{} (segment {})", v, intensity_to_segment(v)));
+                }
+                ui.end_row();
+            }
+
+            ui.label(RichText::new("Pattern").strong());
+            for s in &latest {
+                let seg = (intensity_to_segment(s.intensity.0), intensity_to_segment(s.intensity.1), intensity_to_segment(s.intensity.2), intensity_to_segment(s.intensity.3));
+                ui.label(profilePatternLookup(profiles, seg).name);
+            }
+            ui.end_row();
+        });
+    }
+
+    let mut record: Vec<&Session> = sessions.iter().filter(|s| s.name == *historyPerson && (historyFocus.is_empty() || s.focus == *historyFocus)).collect();
+    record.sort_by_key(|s| s.date());
+
+    if focuses.len() > 1 && historyFocus.is_empty() {
+        ui.add_space(5.0);
+        ui.small("The timeline mixes every focus; choose one to compare like with like.");
+    }
+
+    // (date, intensity, segments, pattern name) of each administration, oldest first.
+    let rows: Vec<(Option<DateTime<Utc>>, [i8; 4], [i8; 4], String)> = record.iter().map(|s| {
+
+        let v = [s.intensity.0, s.intensity.1, s.intensity.2, s.intensity.3];
+        let seg = [intensity_to_segment(v[0]), intensity_to_segment(v[1]), intensity_to_segment(v[2]), intensity_to_segment(v[3])];
+
+        (s.date(), v, seg, profilePatternLookup(profiles, (seg[0], seg[1], seg[2], seg[3])).name)
+    }).collect();
+
+    ui.add_space(10.0);
+
+    egui::ScrollArea::vertical().show(ui, |ui| {
+
+        let names = ["D", "i", "S", "C"];
+        let colors = [Color32::from_rgb(230, 90, 80), Color32::from_rgb(240, 200, 80), Color32::from_rgb(110, 190, 110), Color32::from_rgb(137, 207, 240)];
+
+        // Undated sessions are placed by their order instead.
+        let dated = rows.iter().all(|r| r.0.is_some());
+        let x = |k: usize| match rows[k].0 { Some(date) if dated => date.timestamp() as f64 / 86400.0, _ => k as f64 };
+
+        Plot::new("History")
+            .height(250.0)
+            .legend(Legend::default())
+            .include_y(1.0)
+            .include_y(28.0)
+            .x_axis_formatter(move |v, _range: &RangeInclusive<f64>| {
+                if dated {
+                    DateTime::from_timestamp((v * 86400.0) as i64, 0).map(|d| d.format("%Y-%m-%d").to_string()).unwrap_or_default()
+                } else {
+                    format!("#{}", v as i64 + 1)
+                }
+            })
+            .show(ui, |plot_ui| {
+
+                for d in 0..4 {
+                    let points: Vec<[f64; 2]> = (0..rows.len()).map(|k| [x(k), rows[k].1[d] as f64]).collect();
+                    plot_ui.line(Line::new(PlotPoints::from(points.clone())).color(colors[d]).width(2.0).name(names[d]));
+                    plot_ui.points(Points::new(PlotPoints::from(points)).color(colors[d]).radius(4.0).name(names[d]));
+                }
+            });
+
+        ui.add_space(10.0);
+
+        egui::Grid::new("History Table").num_columns(6).striped(true).min_col_width(ui.available_width() / 8.0).show(ui, |ui| {
+
+            for heading in ["Date", "D", "i", "S", "C", "Pattern"] {
+                ui.label(RichText::new(heading).strong().color(Color32::from_rgb(137, 207, 240)));
+            }
+            ui.end_row();
+
+            for (k, row) in rows.iter().enumerate() {
+
+                ui.label(row.0.map(|d| d.format("%Y-%m-%d").to_string()).unwrap_or("Undated".to_owned()));
+
+                for d in 0..4 {
+
+                    let text = format!("{} (segment {})", row.1[d], row.2[d]);
+
+                    // Segment changes since the previous administration stand out.
+                    if k > 0 && rows[k - 1].2[d] != row.2[d] {
+                        let arrow = if row.2[d] > rows[k - 1].2[d] { "▲" } else { "▼" };
+                        ui.label(RichText::new(format!("{} {}", text, arrow)).strong().color(Color32::from_rgb(240, 170, 137)));
+                    } else {
+                        ui.label(text);
+                    }
+                }
+
+                ui.label(&row.3);
+                ui.end_row();
+            }
+        });
+
+        ui.add_space(10.0);
+
+        for k in 1..rows.len() {
+            if rows[k].3 != rows[k - 1].3 {
+                let when = rows[k].0.map(|d| d.format("%Y-%m-%d").to_string()).unwrap_or(format!("Session {}", k + 1));
+                ui.label(format!("{}: the classical pattern changed from {} to {}.", when, rows[k - 1].3, rows[k].3));
+            }
+        }
+    });
+}
+
+fn show_team_page(collab: &mut Collab, isAdmin: bool, ctx: &Context, ui: &mut Ui) {
+
+    ui.horizontal(|ui| {
+        ui.label("Server");
+        ui.add_enabled(collab.role == Role::NONE, egui::TextEdit::singleline(&mut collab.server));
+    });
+
+    ui.small("Team sessions go through a disc-server running on your network; nothing leaves it.");
+    ui.add_space(10.0);
+
+    let (code, joined, submitted, board, error) = {
+        let shared = collab.shared.lock().unwrap();
+        (shared.code.to_owned(), shared.joined, shared.submitted, shared.board.clone(), shared.error.to_owned())
+    };
+
+    match collab.role {
+        Role::NONE => {
+
+            ui.label(RichText::new("Join a Session").strong().color(Color32::from_rgb(137, 207, 240)));
+
+            egui::Grid::new("Join").num_columns(2).show(ui, |ui| {
+                ui.label("Session Code");
+                ui.text_edit_singleline(&mut collab.code);
+                ui.end_row();
+                ui.label("Your Name");
+                ui.text_edit_singleline(&mut collab.name);
+                ui.end_row();
+            });
+
+            if ui.add_enabled(!collab.code.trim().is_empty() && !collab.name.trim().is_empty(), egui::Button::new("Join")).clicked() {
+                collab.join(ctx);
+            }
+
+            if isAdmin {
+
+                ui.add_space(20.0);
+                ui.label(RichText::new("Facilitate").strong().color(Color32::from_rgb(137, 207, 240)));
+
+                if ui.button("Start Session").on_hover_text("Opens a session code for participants to join").clicked() {
+                    collab.start(ctx);
+                }
+            }
+        },
+        Role::Participant => {
+
+            if submitted {
+                ui.label(format!("Your answers were sent to session {}.", code));
+            } else if joined {
+                ui.label(format!("Joined session {} as {}. Your answers are sent when you press Next on the Response Page.", code, collab.name.trim()));
+            } else if error.is_empty() {
+                ui.spinner();
+            }
+
+            if ui.button("Leave Session").clicked() { collab.leave(); }
+        },
+        Role::Facilitator => {
+
+            collab.poll(ctx);
+
+            if code.is_empty() {
+                if error.is_empty() { ui.spinner(); }
+            } else {
+                ui.label(RichText::new(format!("Session Code  {}", code)).heading().strong());
+                ui.label(format!("{} of {} participants finished", board.iter().filter(|p| p.complete).count(), board.len()));
+            }
+
+            ui.add_space(10.0);
+
+            egui::Grid::new("Team Board")
+                .num_columns(7)
+                .striped(true)
+                .min_col_width(ui.available_width() / 10.0)
+                .show(ui, |ui| {
+
+                    for heading in ["Name", "Status", "D", "i", "S", "C", "Pattern"] {
+                        ui.label(RichText::new(heading).strong().color(Color32::from_rgb(137, 207, 240)));
+                    }
+                    ui.end_row();
+
+                    for entry in &board {
+
+                        ui.label(&entry.name);
+                        ui.label(if entry.complete { "Finished" } else { "Answering" });
+
+                        match &entry.result {
+                            Some(result) => {
+                                for (v, seg) in [(result.intensity.d, result.segments.d), (result.intensity.i, result.segments.i), (result.intensity.s, result.segments.s), (result.intensity.c, result.segments.c)] {
+                                    ui.label(format!("{} ({})", v, seg));
+                                }
+                                ui.label(&result.pattern.name);
+                            },
+                            None => { for _ in 0..5 { ui.label(""); } }
+                        }
+                        ui.end_row();
+                    }
+
+                    let results: Vec<_> = board.iter().filter_map(|p| p.result.as_ref()).collect();
+
+                    if !results.is_empty() {
+
+                        ui.label(RichText::new("Team Average").strong());
+                        ui.label("");
+
+                        let n = results.len() as f64;
+                        ui.label(format!("{:.1}", results.iter().map(|r| r.intensity.d as f64).sum::<f64>() / n));
+                        ui.label(format!("{:.1}", results.iter().map(|r| r.intensity.i as f64).sum::<f64>() / n));
+                        ui.label(format!("{:.1}", results.iter().map(|r| r.intensity.s as f64).sum::<f64>() / n));
+                        ui.label(format!("{:.1}", results.iter().map(|r| r.intensity.c as f64).sum::<f64>() / n));
+                        ui.label("");
+                        ui.end_row();
+                    }
+                });
+
+            ui.add_space(10.0);
+            if ui.button("End Session").clicked() { collab.leave(); }
+        }
+    }
+
+    if !error.is_empty() {
+        ui.colored_label(Color32::from_rgb(240, 170, 137), error);
+    }
+}
+
+/// Warning badges for reasons to discount a result; hover one for the details.
+fn show_quality_badges(flags: &Vec<QualityFlag>, ui: &mut Ui) {
+
+    if flags.is_empty() {
+        return;
+    }
+
+    ui.horizontal_wrapped(|ui| {
+
+        for flag in flags {
+
+            egui::Frame::none()
+                .fill(Color32::from_rgb(240, 170, 137))
+                .rounding(4.0)
+                .inner_margin(Vec2 { x: 6.0, y: 2.0 })
+                .show(ui, |ui| { ui.label(RichText::new(format!("⚠ {}", flag.title)).color(Color32::BLACK)); })
+                .response
+                .on_hover_text(&flag.detail);
+        }
+    });
+
+    ui.add_space(10.0);
+}
+
+/// Summary metrics and every click on an answer sheet, oldest first.
+fn show_timeline(started: Option<DateTime<Utc>>, events: &Vec<ResponseEvent>, groups: usize, ui: &mut Ui) {
+
+    if events.is_empty() {
+        ui.small("No answers were recorded for this sheet.");
+        return;
+    }
+
+    let summary = summarize(started, events, groups);
+
+    ui.horizontal_wrapped(|ui| {
+        ui.label(format!("Total time {}", format_duration(summary.total)));
+        ui.add_space(20.0);
+        ui.label(format!("{} answers", events.len()));
+        ui.add_space(20.0);
+        ui.label(format!("{} revisions", summary.revisions));
+    });
+
+    ui.add_space(5.0);
+
+    let bars: Vec<Bar> = summary.per_group.iter().enumerate().map(|(g, t)| Bar::new((g + 1) as f64, *t).width(0.8).name(format!("Group {}", g + 1))).collect();
+
+    Plot::new("Time Per Group")
+        .height(120.0)
+        .allow_drag(false)
+        .allow_zoom(false)
+        .allow_scroll(false)
+        .y_axis_formatter(|y, _range: &RangeInclusive<f64>| format_duration(y))
+        .show(ui, |plot_ui| {
+            plot_ui.bar_chart(BarChart::new(bars).color(Color32::from_rgb(137, 207, 240)).element_formatter(Box::new(|bar, _chart| format!("{}\n{}", bar.name, format_duration(bar.value)))));
+        });
+
+    ui.add_space(5.0);
+
+    let start = started.unwrap_or(events[0].at);
+
+    egui::ScrollArea::vertical().id_source(("Timeline", start.timestamp_millis())).max_height(200.0).show(ui, |ui| {
+
+        egui::Grid::new(("Timeline Events", start.timestamp_millis())).striped(true).num_columns(4).show(ui, |ui| {
+
+            for event in events {
+                ui.small(format_duration((event.at - start).num_milliseconds() as f64 / 1000.0));
+                ui.small(format!("Group {}", event.group + 1));
+                ui.small(match event.column { Column::Most => "MOST", Column::Least => "LEAST" });
+                ui.small(&event.word);
+                ui.end_row();
+            }
         });
     });
 }
 
-fn show_profile_section(p: &mut Profile, ui: &mut Ui) {
+/// Shown instead of every page while encrypted sessions are locked.
+fn show_vault_unlock(sealedSessions: &mut Option<Sealed>, vaultKey: &mut Option<VaultKey>, sessions: &mut Vec<Session>, vaultPassphrase: &mut String, vaultStatus: &mut String, adminMode: (&mut AdminConfig, &mut bool, &mut String, &mut String), kiosk: bool, ui: &mut Ui) {
+
+    ui.label("Saved sessions are encrypted. Enter the storage passphrase to continue.");
 
-    ui.group(|ui| {
+    ui.horizontal(|ui| {
 
-        ui.add_space(5.0);
-        ui.label(RichText::new(p.name.to_owned() + " Pattern").strong());
-        ui.add_space(15.0);
+        let field = ui.add(egui::TextEdit::singleline(vaultPassphrase).password(true));
 
-        ui.horizontal_wrapped(|ui| {
+        if ui.button("Unlock").clicked() || (field.lost_focus() && ui.input(|i| i.key_pressed(egui::Key::Enter))) {
 
-            for pair in &p.aspects {
+            if let Some(sealed) = sealedSessions {
 
-                ui.small(RichText::new(pair.0.to_owned()).strong().color(Color32::from_rgb(137, 207, 240)));
-                ui.small(RichText::new(pair.1.to_owned()));
-                ui.end_row();
+                match VaultKey::unlock(sealed, vaultPassphrase) {
+                    Ok((key, opened)) => {
+                        *sessions = opened;
+                        *vaultKey = Some(key);
+                        vaultStatus.clear();
+                    },
+                    Err(e) => *vaultStatus = e
+                }
             }
-        });
 
-        ui.add_space(20.0);
+            vaultPassphrase.zeroize();
+        }
+    });
 
-        ui.horizontal_wrapped(|ui| {
+    ui.small(vaultStatus.to_owned());
 
-            ui.small(RichText::new(p.content.to_owned()));
-            ui.end_row();
-        });
-    });
-}
+    ui.add_space(20.0);
 
-fn show_settings_page(fontSizes: &mut (f32, f32, f32, f32, f32), ui: &mut Ui) {
+    // Deleting everyone's sessions is a facilitator's call, never a participant's.
+    let (admin, adminUnlocked, passphraseInput, adminStatus) = adminMode;
 
-    ui.add(egui::Slider::new(&mut fontSizes.0, 8.0..=32.0).text("Heading"));
-    ui.add(egui::Slider::new(&mut fontSizes.1, 8.0..=32.0).text("Body"));
-    //ui.add(egui::Slider::new(&mut fontSizes.2, 8.0..=32.0).text("Monospace"));
-    ui.add(egui::Slider::new(&mut fontSizes.3, 8.0..=32.0).text("Button"));
-    ui.add(egui::Slider::new(&mut fontSizes.4, 8.0..=32.0).text("Small"));
+    if admin.is_protected() && !*adminUnlocked {
+        show_admin_unlock(admin, adminUnlocked, passphraseInput, adminStatus, ui);
+        return;
+    }
+
+    if kiosk && !admin.is_protected() {
+        ui.small("If the passphrase is lost, start the program without --kiosk to delete the encrypted sessions.");
+        return;
+    }
+
+    if ui.button("Forgot Passphrase").on_hover_text("Deletes the encrypted sessions so the program can be used again").double_clicked() {
+        wipe_sessions(sessions, sealedSessions, vaultKey);
+        vaultStatus.clear();
+    }
+
+    ui.small("Double click to delete the encrypted sessions. They cannot be recovered.");
 }
 
 // Helper Functions
 
-fn process(responses: &mut Vec<(Choice, Choice)>, tally: &mut (i8, i8, i8, i8), intensity: &mut (i8, i8, i8, i8)) {
+pub(crate) fn process(responses: &mut Vec<(Choice, Choice)>, tally: &mut (i8, i8, i8, i8), intensity: &mut (i8, i8, i8, i8)) {
 
     tally.0 = 0;
     tally.1 = 0;
@@ -958,12 +3190,12 @@ fn process(responses: &mut Vec<(Choice, Choice)>, tally: &mut (i8, i8, i8, i8),
     intensity.3 = (27.31404101 / (1.0 + 0.5608447664 * E.powf(-0.2479183241  * tally.3 as f64))).clamp(1.0, 28.0) as i8; // Logistic Regression
 }
 
-fn intensity_to_segment(val: i8) -> i8 {
+pub(crate) fn intensity_to_segment(val: i8) -> i8 {
 
     return ((val - 1) / 4) + 1;
 }
 
-fn profilePatternLookup(profiles: &mut Vec<Profile>, seg: (i8, i8, i8, i8)) -> Profile {
+pub(crate) fn profilePatternLookup(profiles: &mut Vec<Profile>, seg: (i8, i8, i8, i8)) -> Profile {
 
     let d = seg.0;
     let i = seg.1;
@@ -996,330 +3228,124 @@ fn r(val: i8, low: i8, high: i8) -> bool {
     return val >= low && val <= high;
 }
 
-fn show_d_highlights(ui: &mut Ui) {
-
-    ui.horizontal_wrapped(|ui| {
-
-        ui.small(RichText::new("DOMINANCE").strong());
-        ui.end_row();
-        ui.small("Emphasis is on shaping the environment by overcoming opposition to accomplish results.");
-        ui.end_row();
-    });
-
-    ui.add_space(5.0);
-    ui.separator();
-    ui.add_space(5.0);
-
-    ui.columns(4, |col| {
-
-        col[0].horizontal_wrapped(|ui| {
-
-            ui.small(RichText::new("This person's tendencies include").color(Color32::from_rgb(137, 207, 240)));
-            ui.end_row();
-            ui.small("• getting immediate results");
-            ui.end_row();
-            ui.small("• causing action");
-            ui.end_row();
-            ui.small("• accepting challanges");
-            ui.end_row();
-            ui.small("• making quick decisions");
-            ui.end_row();
-            ui.small("• questioning the status quo");
-            ui.end_row();
-            ui.small("• taking authority");
-            ui.end_row();
-            ui.small("• managing trouble");
-            ui.end_row();
-            ui.small("• solving problems");
-            ui.end_row();
-        });
-        
-        col[1].horizontal_wrapped(|ui| {
-
-            ui.small(RichText::new("This person desires an environment that includes").color(Color32::from_rgb(137, 207, 240)));
-            ui.end_row();
-            ui.small("• power and authority");
-            ui.end_row();
-            ui.small("• prestige and challenge");
-            ui.end_row();
-            ui.small("• opportunities for individual accomplishments");
-            ui.end_row();
-            ui.small("• a wipe scrope of operations");
-            ui.end_row();
-            ui.small("• direct answers");
-            ui.end_row();
-            ui.small("• opportunities for advancement");
-            ui.end_row();
-            ui.small("• freedom from controls and supervision");
-            ui.end_row();
-            ui.small("• many new and varied activities");
-            ui.end_row();
-        });
-
-        col[2].horizontal_wrapped(|ui| {
-
-            ui.small(RichText::new("This person needs others who").color(Color32::from_rgb(137, 207, 240)));
-            ui.end_row();
-            ui.small("• weigh pros and cons");
-            ui.end_row();
-            ui.small("• calculate risks");
-            ui.end_row();
-            ui.small("• use caution");
-            ui.end_row();
-            ui.small("• create a predictable environment");
-            ui.end_row();
-            ui.small("• research facts");
-            ui.end_row();
-            ui.small("• deliberate before deciding");
-            ui.end_row();
-            ui.small("• recognize the needs of others");
-            ui.end_row();
-        });
-
-        col[3].horizontal_wrapped(|ui| {
-
-            ui.small(RichText::new("To be more effective, this person needs").color(Color32::from_rgb(137, 207, 240)));
-            ui.end_row();
-            ui.small("• to receive difficult assignments");
-            ui.end_row();
-            ui.small("• to understand that they need people");
-            ui.end_row();
-            ui.small("• to base techniques on practical experience");
-            ui.end_row();
-            ui.small("• to receive an occasional shock");
-            ui.end_row();
-            ui.small("• to identify with a group");
-            ui.end_row();
-            ui.small("• to verbalize reasons for conclusions");
-            ui.end_row();
-            ui.small("• to be aware of existing sanctions");
-            ui.end_row();
-            ui.small("• to pace self and to relax more");
-            ui.end_row();
-        });
-    });
+fn to_array(val: (i8, i8, i8, i8)) -> [f64; 4] {
 
-    ui.add_space(25.0);
+    return [val.0 as f64, val.1 as f64, val.2 as f64, val.3 as f64];
 }
 
-fn show_i_highlights(ui: &mut Ui) {
-
-    ui.horizontal_wrapped(|ui| {
+fn round_to_tenth(val: f64) -> f64 {
 
-        ui.small(RichText::new("INFLUENCE").strong());
-        ui.end_row();
-        ui.small("Emphasis is on shaping the environment by influencing or persuading others.");
-        ui.end_row();
-    });
+    return (val * 10.0).round() / 10.0;
+}
 
-    ui.add_space(5.0);
-    ui.separator();
-    ui.add_space(5.0);
+/// Overwrites session contents in memory before dropping them.
+fn zeroize_sessions(sessions: &mut Vec<Session>) {
+
+    for session in sessions.iter_mut() {
+        session.name.zeroize();
+        session.responses.iter_mut().for_each(|r| *r = (Choice::NONE, Choice::NONE));
+        session.tally = (0, 0, 0, 0);
+        session.intensity = (0, 0, 0, 0);
+        session.events.iter_mut().for_each(|e| e.word.zeroize());
+        session.events.clear();
+        session.focus.zeroize();
+        session.descriptors.iter_mut().flatten().for_each(|d| d.zeroize());
+        session.reflection.notes.zeroize();
+        session.reflection.resonant.iter_mut().for_each(|r| r.zeroize());
+        session.reflection.actions.iter_mut().for_each(|a| a.text.zeroize());
+        session.reflection = Reflection::default();
+    }
 
-    ui.columns(4, |col| {
+    sessions.clear();
+}
 
-        col[0].horizontal_wrapped(|ui| {
+fn wipe_sessions(sessions: &mut Vec<Session>, sealedSessions: &mut Option<Sealed>, vaultKey: &mut Option<VaultKey>) {
 
-            ui.small(RichText::new("This person's tendencies include").color(Color32::from_rgb(137, 207, 240)));
-            ui.end_row();
-            ui.small("• contacting people");
-            ui.end_row();
-            ui.small("• making a favorable impression");
-            ui.end_row();
-            ui.small("• being articulate");
-            ui.end_row();
-            ui.small("• creating a motivating environment");
-            ui.end_row();
-            ui.small("• generating enthusiasm");
-            ui.end_row();
-            ui.small("• entertaining people");
-            ui.end_row();
-            ui.small("• viewing people and situations with optimism");
-            ui.end_row();
-            ui.small("• participating in a group");
-            ui.end_row();
-        });
-        
-        col[1].horizontal_wrapped(|ui| {
+    zeroize_sessions(sessions);
 
-            ui.small(RichText::new("This person desires an environment that includes").color(Color32::from_rgb(137, 207, 240)));
-            ui.end_row();
-            ui.small("• popularity, social recognition");
-            ui.end_row();
-            ui.small("• public recognition of ability");
-            ui.end_row();
-            ui.small("• freedom of expression");
-            ui.end_row();
-            ui.small("• group activities outside of job");
-            ui.end_row();
-            ui.small("• democratic relationships");
-            ui.end_row();
-            ui.small("• freedom from control and detail");
-            ui.end_row();
-            ui.small("• opportunities to verbalize proposals");
-            ui.end_row();
-            ui.small("• coaching and counseling");
-            ui.end_row();
-            ui.small("• favorable working conditions");
-            ui.end_row();
-        });
+    if let Some(sealed) = sealedSessions.as_mut() {
+        *sealed = Sealed::default();
+    }
 
-        col[2].horizontal_wrapped(|ui| {
+    *sealedSessions = None;
+    *vaultKey = None;
+}
 
-            ui.small(RichText::new("This person needs others who").color(Color32::from_rgb(137, 207, 240)));
-            ui.end_row();
-            ui.small("• concentrate on the task");
-            ui.end_row();
-            ui.small("• seek facts");
-            ui.end_row();
-            ui.small("• speak directly");
-            ui.end_row();
-            ui.small("• respect sincerity");
-            ui.end_row();
-            ui.small("• develop systematic approaches");
-            ui.end_row();
-            ui.small("• prefer to deal with things instead of people");
-            ui.end_row();
-            ui.small("• take a logical approach");
-            ui.end_row();
-            ui.small("• demonstrate individual follow-through");
-            ui.end_row();
-        });
+fn comparison_name(comparison: Comparison, sessions: &Vec<Session>) -> String {
 
-        col[3].horizontal_wrapped(|ui| {
+    match comparison {
+        Comparison::NONE => "Nothing".to_owned(),
+        Comparison::Session(k) => {
 
-            ui.small(RichText::new("To be more effective, this person needs").color(Color32::from_rgb(137, 207, 240)));
-            ui.end_row();
-            ui.small("• to control time, if 'D' or 'S' is low");
-            ui.end_row();
-            ui.small("• to make objective decisions");
-            ui.end_row();
-            ui.small("• to use hands-on management");
-            ui.end_row();
-            ui.small("• to be more realistic when appraising others");
-            ui.end_row();
-            ui.small("• to make priorities and deadlines");
-            ui.end_row();
-            ui.small("• to be more firm with others, if D is low");
-            ui.end_row();
-        });
-    });
+            let mut details: Vec<String> = vec![];
+            if !sessions[k].focus.is_empty() { details.push(sessions[k].focus.to_owned()); }
+            if let Some(date) = sessions[k].date() { details.push(date.format("%Y-%m-%d").to_string()); }
 
-    ui.add_space(25.0);
+            if details.is_empty() { sessions[k].name.to_owned() } else { format!("{} ({})", sessions[k].name, details.join(", ")) }
+        },
+        Comparison::TeamAverage => format!("Team Average ({})", sessions.len())
+    }
 }
 
-fn show_s_highlights(ui: &mut Ui) {
-
-    ui.horizontal_wrapped(|ui| {
+/// Adjectives for every segment of each dimension, with the participant's own segment picked out.
+/// Returns whether a word was ticked or unticked.
+fn show_descriptor_chart(highlights: &Vec<DimensionHighlights>, intensity: (i8, i8, i8, i8), descriptorTicks: &mut [Vec<String>; 4], ui: &mut Ui) -> bool {
 
-        ui.small(RichText::new("STEADINESS").strong());
-        ui.end_row();
-        ui.small("Emphasis is on cooperating with others within existing circumstances to carry out the task.");
-        ui.end_row();
-    });
+    let seg = [intensity_to_segment(intensity.0), intensity_to_segment(intensity.1), intensity_to_segment(intensity.2), intensity_to_segment(intensity.3)];
+    let mut changed = false;
 
-    ui.add_space(5.0);
-    ui.separator();
+    ui.small("Tick the words that describe you.");
     ui.add_space(5.0);
 
-    ui.columns(4, |col| {
+    egui::Grid::new("Segment Descriptors")
+        .num_columns(5)
+        .striped(true)
+        .min_col_width(ui.available_width() / 5.0)
+        .show(ui, |ui| {
 
-        col[0].horizontal_wrapped(|ui| {
+            for segment in (1..=7).rev() {
 
-            ui.small(RichText::new("This person's tendencies include").color(Color32::from_rgb(137, 207, 240)));
-            ui.end_row();
-            ui.small("• performing in a consistent, predictable manner");
-            ui.end_row();
-            ui.small("• demonstrating patience");
-            ui.end_row();
-            ui.small("• developing specialized skills");
-            ui.end_row();
-            ui.small("• helping others");
-            ui.end_row();
-            ui.small("• showing loyalty");
-            ui.end_row();
-            ui.small("• being a good listener");
-            ui.end_row();
-            ui.small("• calming excited people");
-            ui.end_row();
-            ui.small("• creating a stable harmonious work environment");
-            ui.end_row();
-        });
-        
-        col[1].horizontal_wrapped(|ui| {
+                ui.label(format!("Segment {}", segment));
 
-            ui.small(RichText::new("This person desires an environment that includes").color(Color32::from_rgb(137, 207, 240)));
-            ui.end_row();
-            ui.small("• maintenance of the status quo unless given reasons for change");
-            ui.end_row();
-            ui.small("• predictable routines");
-            ui.end_row();
-            ui.small("• credit for work accomplished");
-            ui.end_row();
-            ui.small("• minimal work infringement on home life");
-            ui.end_row();
-            ui.small("• sincere appreciation");
-            ui.end_row();
-            ui.small("• identification with a group");
-            ui.end_row();
-            ui.small("• standard operating procedures");
-            ui.end_row();
-            ui.small("• minimal conflicts");
-            ui.end_row();
-        });
+                for d in 0..4 {
 
-        col[2].horizontal_wrapped(|ui| {
+                    ui.vertical(|ui| {
 
-            ui.small(RichText::new("This person needs others who").color(Color32::from_rgb(137, 207, 240)));
-            ui.end_row();
-            ui.small("• react quickly to unexpected change");
-            ui.end_row();
-            ui.small("• stretch toward the challenges of accepted tasks");
-            ui.end_row();
-            ui.small("• become involved in more than one thing");
-            ui.end_row();
-            ui.small("• are self-promoting");
-            ui.end_row();
-            ui.small("• apply pressure on others");
-            ui.end_row();
-            ui.small("• work comfortably in an unpredictable environment");
-            ui.end_row();
-            ui.small("• help to prioritize work");
-            ui.end_row();
-            ui.small("• are flexible in work procedures");
-            ui.end_row();
-        });
+                        for word in highlights[d].descriptors_at(segment) {
 
-        col[3].horizontal_wrapped(|ui| {
+                            let mut ticked = descriptorTicks[d].contains(word);
+                            let text = if segment == seg[d] { RichText::new(word).strong().color(Color32::from_rgb(137, 207, 240)) } else { RichText::new(word) };
 
-            ui.small(RichText::new("To be more effective, this person needs").color(Color32::from_rgb(137, 207, 240)));
-            ui.end_row();
-            ui.small("• to be conditioned prior to change");
-            ui.end_row();
-            ui.small("• to validate self-worth");
-            ui.end_row();
-            ui.small("• to know how personal effort contributes to the group effort");
-            ui.end_row();
-            ui.small("• to have colleagues of similar competence and sincerity");
-            ui.end_row();
-            ui.small("• to know task guidelines");
-            ui.end_row();
-            ui.small("• to have creativity encouraged");
-            ui.end_row();
+                            if ui.checkbox(&mut ticked, text).changed() {
+
+                                if ticked { descriptorTicks[d].push(word.to_owned()); } else { descriptorTicks[d].retain(|w| w != word); }
+                                changed = true;
+                            }
+                        }
+                    });
+                }
+
+                ui.end_row();
+            }
         });
-    });
 
-    ui.add_space(25.0);
+    return changed;
 }
 
-fn show_c_highlights(ui: &mut Ui) {
+fn show_highlights(h: &DimensionHighlights, segment: i8, ui: &mut Ui) {
 
     ui.horizontal_wrapped(|ui| {
 
-        ui.small(RichText::new("CONSCIENTIOUSNESS").strong());
+        ui.small(RichText::new(h.title.to_owned()).strong());
         ui.end_row();
-        ui.small("Emphasis is on working conscientiously within existing circumstances to ensure quality and accuracy.");
+        ui.small(h.emphasis.to_owned());
         ui.end_row();
+
+        if let Some(n) = h.narrative(segment) {
+            ui.small(RichText::new(format!("{} (segment {}): ", n.label, segment)).color(Color32::from_rgb(137, 207, 240)));
+            ui.small(n.text.to_owned());
+            ui.end_row();
+        }
     });
 
     ui.add_space(5.0);
@@ -1328,86 +3354,20 @@ fn show_c_highlights(ui: &mut Ui) {
 
     ui.columns(4, |col| {
 
-        col[0].horizontal_wrapped(|ui| {
-
-            ui.small(RichText::new("This person's tendencies include").color(Color32::from_rgb(137, 207, 240)));
-            ui.end_row();
-            ui.small("• adhering to key directives and standards");
-            ui.end_row();
-            ui.small("• concentratingon key details");
-            ui.end_row();
-            ui.small("• thinking analytically, weighing pros and cons");
-            ui.end_row();
-            ui.small("• being diplomatic with people");
-            ui.end_row();
-            ui.small("• using subtle or indirect approaches to conflict");
-            ui.end_row();
-            ui.small("• checking for accuracy");
-            ui.end_row();
-            ui.small("• analyzing performance critically");
-            ui.end_row();
-            ui.small("• using a systematic approach to situations or activities");
-            ui.end_row();
-        });
-        
-        col[1].horizontal_wrapped(|ui| {
-
-            ui.small(RichText::new("This person desires an environment that includes").color(Color32::from_rgb(137, 207, 240)));
-            ui.end_row();
-            ui.small("• clearly defined performance expectations");
-            ui.end_row();
-            ui.small("• values of quality and accuracy");
-            ui.end_row();
-            ui.small("• a reserved, business-like atmosphere");
-            ui.end_row();
-            ui.small("• opportunities to demonstrate expertise");
-            ui.end_row();
-            ui.small("• control over factors that affect their performance");
-            ui.end_row();
-            ui.small("• opportunities to ask \"why\" questions");
-            ui.end_row();
-            ui.small("• recognition for specific skills and accomplishments");
-            ui.end_row();
-        });
-
-        col[2].horizontal_wrapped(|ui| {
+        for (k, (heading, list)) in HIGHLIGHT_HEADINGS.iter().zip(h.lists()).enumerate() {
 
-            ui.small(RichText::new("This person needs others who").color(Color32::from_rgb(137, 207, 240)));
-            ui.end_row();
-            ui.small("• delegate important tasks");
-            ui.end_row();
-            ui.small("• make quick decisions");
-            ui.end_row();
-            ui.small("• use policies only as guidelines");
-            ui.end_row();
-            ui.small("• compromise with the opposition");
-            ui.end_row();
-            ui.small("• state unpopular positions");
-            ui.end_row();
-            ui.small("• initiate and facilitate discussions");
-            ui.end_row();
-            ui.small("• encourage teamwork");
-            ui.end_row();
-        });
+            col[k].horizontal_wrapped(|ui| {
 
-        col[3].horizontal_wrapped(|ui| {
+                ui.small(RichText::new(*heading).color(Color32::from_rgb(137, 207, 240)));
+                ui.end_row();
 
-            ui.small(RichText::new("To be more effective, this person needs").color(Color32::from_rgb(137, 207, 240)));
-            ui.end_row();
-            ui.small("• to have time to plan carefully");
-            ui.end_row();
-            ui.small("• to know exact job descriptions and performance objectives");
-            ui.end_row();
-            ui.small("• to schedule performance appraisals");
-            ui.end_row();
-            ui.small("• to receieve specific feedback on performance");
-            ui.end_row();
-            ui.small("• to respect people's personal worth as much as their accomplishments");
-            ui.end_row();
-            ui.small("• to develop tolerance for conflict");
-            ui.end_row();
-        });
+                for item in list {
+                    ui.small("• ".to_owned() + item);
+                    ui.end_row();
+                }
+            });
+        }
     });
 
     ui.add_space(25.0);
-}
\ No newline at end of file
+}
@@ -0,0 +1,141 @@
+use chrono::{DateTime, Utc};
+
+#[derive(serde::Deserialize, serde::Serialize, PartialEq, Clone, Copy, Debug)]
+pub(crate) enum Column { Most, Least }
+
+/// One click on a MOST or LEAST radio button on the Response page.
+#[derive(serde::Deserialize, serde::Serialize, PartialEq, Clone, Debug)]
+pub(crate) struct ResponseEvent {
+    pub(crate) group: usize,
+    pub(crate) word: String,
+    pub(crate) column: Column,
+    pub(crate) at: DateTime<Utc>
+}
+
+/// How long an answer sheet took and how often answers were changed.
+pub(crate) struct AuditSummary {
+    /// Seconds from the Response page opening to the last answer.
+    pub(crate) total: f64,
+
+    /// Seconds spent on each group: every event is credited with the time since the event before it.
+    pub(crate) per_group: Vec<f64>,
+
+    /// Answers replaced after a group's column had already been answered.
+    pub(crate) revisions: usize
+}
+
+pub(crate) fn summarize(started: Option<DateTime<Utc>>, events: &[ResponseEvent], groups: usize) -> AuditSummary {
+
+    let mut summary = AuditSummary { total: 0.0, per_group: vec![0.0; groups], revisions: 0 };
+    let mut answered: Vec<(bool, bool)> = vec![(false, false); groups];
+
+    let mut previous = match started.or(events.first().map(|e| e.at)) {
+        Some(t) => t,
+        None => return summary
+    };
+    let first = previous;
+
+    for event in events {
+
+        let seconds = seconds_between(previous, event.at);
+        previous = event.at;
+
+        if event.group >= groups {
+            continue;
+        }
+
+        summary.per_group[event.group] += seconds;
+
+        let seen = match event.column {
+            Column::Most => &mut answered[event.group].0,
+            Column::Least => &mut answered[event.group].1
+        };
+
+        if *seen { summary.revisions += 1; }
+        *seen = true;
+    }
+
+    summary.total = seconds_between(first, previous);
+    return summary;
+}
+
+/// Formats seconds as `m:ss`.
+pub(crate) fn format_duration(seconds: f64) -> String {
+
+    let s = seconds.max(0.0).round() as u64;
+    return format!("{}:{:02}", s / 60, s % 60);
+}
+
+fn seconds_between(from: DateTime<Utc>, to: DateTime<Utc>) -> f64 {
+
+    return (to - from).num_milliseconds().max(0) as f64 / 1000.0;
+}
+
+#[cfg(test)]
+mod tests {
+
+    use chrono::TimeZone;
+
+    use super::*;
+
+    fn event(group: usize, column: Column, second: u32) -> ResponseEvent {
+
+        return ResponseEvent { group, word: "word".to_owned(), column, at: Utc.with_ymd_and_hms(2024, 1, 1, 9, 0, second).unwrap() };
+    }
+
+    #[test]
+    fn summarize_credits_each_group_with_the_time_before_its_events() {
+
+        let started = Utc.with_ymd_and_hms(2024, 1, 1, 9, 0, 0).single();
+        let events = [event(0, Column::Most, 4), event(0, Column::Least, 6), event(1, Column::Most, 15), event(1, Column::Least, 20)];
+
+        let summary = summarize(started, &events, 2);
+
+        assert_eq!(summary.total, 20.0);
+        assert_eq!(summary.per_group, vec![6.0, 14.0]);
+        assert_eq!(summary.revisions, 0);
+    }
+
+    #[test]
+    fn summarize_counts_answers_replaced_in_the_same_column() {
+
+        // MOST in group 0 is answered three times (two changes); group 1's LEAST once, then its MOST once.
+        let events = [event(0, Column::Most, 1), event(0, Column::Most, 2), event(1, Column::Least, 3),
+                      event(0, Column::Most, 4), event(1, Column::Most, 5), event(0, Column::Least, 6)];
+
+        let summary = summarize(None, &events, 2);
+
+        assert_eq!(summary.revisions, 2);
+        assert_eq!(summary.total, 5.0);
+    }
+
+    #[test]
+    fn summarize_ignores_groups_the_form_does_not_have() {
+
+        let events = [event(0, Column::Most, 1), event(5, Column::Most, 3), event(5, Column::Most, 4)];
+
+        let summary = summarize(None, &events, 1);
+
+        assert_eq!(summary.per_group, vec![0.0]);
+        assert_eq!(summary.revisions, 0);
+        assert_eq!(summary.total, 3.0);
+    }
+
+    #[test]
+    fn summarize_without_events_or_start_is_empty() {
+
+        let summary = summarize(None, &[], 3);
+
+        assert_eq!(summary.total, 0.0);
+        assert_eq!(summary.per_group, vec![0.0; 3]);
+        assert_eq!(summary.revisions, 0);
+    }
+
+    #[test]
+    fn format_duration_pads_seconds() {
+
+        assert_eq!(format_duration(0.0), "0:00");
+        assert_eq!(format_duration(65.4), "1:05");
+        assert_eq!(format_duration(-3.0), "0:00");
+    }
+}
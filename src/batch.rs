@@ -0,0 +1,101 @@
+use std::path::{Path, PathBuf};
+
+use crate::answers::{AnswerSheet, parse_answers};
+use crate::app::{Choice, GraphType, Profile, process, intensity_to_segment, profilePatternLookup};
+use crate::highlights::DimensionHighlights;
+use crate::reflection::Reflection;
+use crate::report::Report;
+
+/// One CSV record: fields joined by commas, quoted where they hold a comma, quote or newline.
+pub(crate) fn csv_line(fields: impl Iterator<Item = String>) -> String {
+
+    let quoted: Vec<String> = fields.map(|f| {
+        if f.contains(|c| c == ',' || c == '"' || c == '\n') { format!("\"{}\"", f.replace('"', "\"\"")) } else { f }
+    }).collect();
+
+    return quoted.join(",") + "\n";
+}
+
+/// What a batch run did: how many files were scored, and each file that wasn't with the reason.
+pub(crate) struct BatchSummary {
+    pub(crate) scored: usize,
+    pub(crate) failed: Vec<(PathBuf, String)>
+}
+
+/// Scores an answer file's text exactly as the Results page scores the Response page.
+pub(crate) fn score_answers(text: &str, questions: &Vec<Vec<(String, Choice, Choice)>>) -> Result<(AnswerSheet, (i8, i8, i8, i8), (i8, i8, i8, i8)), String> {
+
+    let mut sheet = parse_answers(text, questions)?;
+
+    let mut tally = (0, 0, 0, 0);
+    let mut intensity = (1, 1, 1, 1);
+    process(&mut sheet.responses, &mut tally, &mut intensity);
+
+    return Ok((sheet, tally, intensity));
+}
+
+/// Scores every answer file in `input`, writing `summary.csv`, one HTML report per file under `reports/` named
+/// after the whole file name (so `alice.txt` and `alice.csv` don't overwrite each other), and `errors.log`
+/// listing the files that could not be scored, all in `out`. A bad file never stops the run.
+pub(crate) fn score_directory(input: &Path, out: &Path, questions: &Vec<Vec<(String, Choice, Choice)>>, profiles: &mut Vec<Profile>, highlights: &[DimensionHighlights], graphType: GraphType) -> Result<BatchSummary, String> {
+
+    let mut files: Vec<PathBuf> = std::fs::read_dir(input).map_err(|e| format!("{}: {}", input.display(), e))?
+        .filter_map(|entry| entry.ok().map(|e| e.path()))
+        .filter(|p| p.is_file() && !p.file_name().map_or(true, |n| n.to_string_lossy().starts_with('.')))
+        .collect();
+
+    files.sort();
+
+    let reports = out.join("reports");
+    std::fs::create_dir_all(&reports).map_err(|e| format!("{}: {}", reports.display(), e))?;
+
+    let mut summary = BatchSummary { scored: 0, failed: vec![] };
+
+    let mut csv = csv_line(["file", "name", "focus", "tally_D", "tally_i", "tally_S", "tally_C", "intensity_D", "intensity_i", "intensity_S", "intensity_C",
+                            "segment_D", "segment_i", "segment_S", "segment_C", "pattern", "report"].iter().map(|s| s.to_string()));
+
+    for file in &files {
+
+        let fileName = file.file_name().map(|n| n.to_string_lossy().into_owned()).unwrap_or_default();
+        let stem = file.file_stem().map(|s| s.to_string_lossy().into_owned()).unwrap_or_default();
+        let report_path = reports.join(format!("{}.html", fileName));
+
+        let scored = std::fs::read_to_string(file).map_err(|e| e.to_string()).and_then(|text| score_answers(&text, questions));
+
+        let (sheet, tally, intensity) = match scored {
+            Ok(s) => s,
+            Err(e) => { summary.failed.push((file.to_owned(), e)); continue; }
+        };
+
+        let seg = (intensity_to_segment(intensity.0), intensity_to_segment(intensity.1), intensity_to_segment(intensity.2), intensity_to_segment(intensity.3));
+        let profile = profilePatternLookup(profiles, seg);
+        let name = sheet.detail("name").unwrap_or(&stem);
+        let focus = sheet.detail("focus").unwrap_or_default();
+
+        let report = Report { name, focus, tally, intensity, graphType, profile: &profile, highlights, reflection: &Reflection::default() };
+
+        if let Err(e) = report.write(&report_path) {
+            summary.failed.push((file.to_owned(), e));
+            continue;
+        }
+
+        let numbers = [tally.0, tally.1, tally.2, tally.3, intensity.0, intensity.1, intensity.2, intensity.3, seg.0, seg.1, seg.2, seg.3];
+        let mut row = vec![fileName.to_owned(), name.to_owned(), focus.to_owned()];
+
+        row.extend(numbers.iter().map(|v| v.to_string()));
+        row.push(profile.name);
+        row.push(format!("reports/{}.html", fileName));
+
+        csv += &csv_line(row.into_iter());
+        summary.scored += 1;
+    }
+
+    let summary_path = out.join("summary.csv");
+    std::fs::write(&summary_path, csv).map_err(|e| format!("{}: {}", summary_path.display(), e))?;
+
+    let log: String = summary.failed.iter().map(|(file, e)| format!("{}: {}\n", file.display(), e)).collect();
+    let log_path = out.join("errors.log");
+    std::fs::write(&log_path, log).map_err(|e| format!("{}: {}", log_path.display(), e))?;
+
+    return Ok(summary);
+}
@@ -0,0 +1,29 @@
+//! Local HTTP API for scoring answer sheets; see `disc::server` for the endpoints.
+//!
+//! `disc-server [--address 127.0.0.1:8080] [--store disc-results] [--token <secret>] [--allow-origin <origin>]`
+
+fn main() {
+
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    let option = |name: &str| args.iter().position(|a| a == name).and_then(|k| args.get(k + 1).cloned());
+
+    // Only listen on this machine unless told otherwise.
+    let address = option("--address").unwrap_or("127.0.0.1:8080".to_owned());
+    let store = option("--store").unwrap_or("disc-results".to_owned());
+
+    // No web page may call the server unless its origin is named, e.g. http://127.0.0.1:8081 for `trunk serve`.
+    let origin = option("--allow-origin");
+
+    let token = match option("--token") {
+        Some(token) => token,
+        None => match disc::server::new_token() {
+            Ok(token) => { println!("results token: {}", token); token },
+            Err(e) => { eprintln!("error: {}", e); std::process::exit(1); }
+        }
+    };
+
+    if let Err(e) = disc::server::serve(&address, std::path::Path::new(&store), origin.as_deref(), &token) {
+        eprintln!("error: {}", e);
+        std::process::exit(1);
+    }
+}
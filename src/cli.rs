@@ -0,0 +1,208 @@
+use std::path::Path;
+
+use crate::app::{Choice, GraphType, Profile, default_questions, default_profiles, intensity_to_segment, profilePatternLookup};
+use crate::answers::AnswerSheet;
+use crate::{batch, graph};
+use crate::highlights::{DimensionHighlights, default_highlights, load_highlights};
+use crate::instrument::{STANDARD_FORM, load_form};
+use crate::library::load_library;
+use crate::paper::write_paper_forms;
+use crate::reflection::Reflection;
+use crate::report::Report;
+
+const USAGE: &str = "Usage:
+    disc                                   Start the DiSC Program window
+    disc --kiosk                           Start fullscreen for participants taking turns on one machine
+    disc graph <answers> [options]         Score an answer file and export its graph
+        --form <file.json>                 Instrument the answers were given on (default: the Standard form)
+        --type <line|bar|radar|circle>     Graph type (default: line)
+        --out <file.svg|file.png>          Output file (default: graph.svg)
+        --width <pixels>                   PNG width in pixels, 200 to 8000 (default: 900)
+    disc report <answers> [options]        Score an answer file and write an HTML report
+        --form <file.json>                 Instrument the answers were given on (default: the Standard form)
+        --type <line|bar|radar|circle>     Graph type (default: line)
+        --out <file.html>                  Output file (default: report.html)
+        --profiles <file.json>             Profile library (default: profiles.json, if present, as the window uses)
+        --highlights <file.json>           Highlights (default: highlights.json, if present, as the window uses)
+    disc batch <directory> [options]       Score every answer file in a directory
+        --type <line|bar|radar|circle>     Graph type (default: line)
+        --form <file.json>                 Instrument the answers were given on (default: the Standard form)
+        --out <directory>                  Where summary.csv, errors.log and reports/ go (default: batch)
+        --profiles <file.json>             Profile library (default: profiles.json, if present, as the window uses)
+        --highlights <file.json>           Highlights (default: highlights.json, if present, as the window uses)
+    disc paper [options]                   Write a printable answer sheet and its scoring key
+        --form <file.json>                 Instrument file (default: the Standard form)
+        --out <file.html>                  Answer sheet (default: answer_sheet.html)
+        --key <file.html>                  Scoring key (default: scoring_key.html)
+    disc help                              Show this message";
+
+/// Runs a command line subcommand, returning the exit code, or `None` when the window should be started instead.
+pub fn run(args: &[String]) -> Option<i32> {
+
+    let command = args.first()?;
+
+    let result = match command.as_str() {
+        "graph" => export_graph(&args[1..]),
+        "report" => export_report(&args[1..]),
+        "batch" => score_batch(&args[1..]),
+        "paper" => export_paper(&args[1..]),
+        "help" | "--help" | "-h" => { println!("{}", USAGE); Ok(()) },
+        "--kiosk" => return None,
+        _ => Err(format!("unknown command \"{}\"\n\n{}", command, USAGE))
+    };
+
+    match result {
+        Ok(()) => Some(0),
+        Err(e) => { eprintln!("error: {}", e); Some(1) }
+    }
+}
+
+fn export_graph(args: &[String]) -> Result<(), String> {
+
+    let input = positional(args).ok_or("missing answer file".to_owned())?;
+    let out = option(args, "--out").unwrap_or("graph.svg".to_owned());
+    let graphType = parse_graph_type(&option(args, "--type").unwrap_or("line".to_owned()))?;
+    let width: u32 = option(args, "--width").unwrap_or("900".to_owned()).parse().map_err(|_| "--width must be a whole number of pixels".to_owned())?;
+
+    if !(200..=8000).contains(&width) {
+        return Err("--width must be between 200 and 8000 pixels".to_owned());
+    }
+
+    let (_sheet, _tally, intensity) = score_file(&input, &questions(args)?)?;
+
+    graph::write(Path::new(&out), graphType, intensity, width)?;
+    println!("wrote {}", out);
+
+    return Ok(());
+}
+
+fn export_report(args: &[String]) -> Result<(), String> {
+
+    let input = positional(args).ok_or("missing answer file".to_owned())?;
+    let out = option(args, "--out").unwrap_or("report.html".to_owned());
+    let graphType = parse_graph_type(&option(args, "--type").unwrap_or("line".to_owned()))?;
+
+    let (sheet, tally, intensity) = score_file(&input, &questions(args)?)?;
+    let seg = (intensity_to_segment(intensity.0), intensity_to_segment(intensity.1), intensity_to_segment(intensity.2), intensity_to_segment(intensity.3));
+
+    let report = Report {
+        name: sheet.detail("name").unwrap_or(&input),
+        focus: sheet.detail("focus").unwrap_or_default(),
+        tally,
+        intensity,
+        graphType,
+        profile: &profilePatternLookup(&mut profiles(args)?, seg),
+        highlights: &highlights(args)?,
+        reflection: &Reflection::default()
+    };
+
+    report.write(Path::new(&out))?;
+    println!("wrote {}", out);
+
+    return Ok(());
+}
+
+fn score_batch(args: &[String]) -> Result<(), String> {
+
+    let input = positional(args).ok_or("missing answer directory".to_owned())?;
+    let out = option(args, "--out").unwrap_or("batch".to_owned());
+    let graphType = parse_graph_type(&option(args, "--type").unwrap_or("line".to_owned()))?;
+
+    let summary = batch::score_directory(Path::new(&input), Path::new(&out), &questions(args)?, &mut profiles(args)?, &highlights(args)?, graphType)?;
+
+    for (file, e) in &summary.failed {
+        eprintln!("skipped {}: {}", file.display(), e);
+    }
+
+    println!("scored {} of {} files into {}", summary.scored, summary.scored + summary.failed.len(), out);
+
+    return Ok(());
+}
+
+fn export_paper(args: &[String]) -> Result<(), String> {
+
+    let out = option(args, "--out").unwrap_or("answer_sheet.html".to_owned());
+    let key = option(args, "--key").unwrap_or("scoring_key.html".to_owned());
+
+    let (name, questions) = match option(args, "--form") {
+        Some(path) => { let form = load_form(Path::new(&path))?; (form.name, form.questions) },
+        None => (STANDARD_FORM.to_owned(), default_questions())
+    };
+
+    write_paper_forms(&name, &questions, Path::new(&out), Path::new(&key))?;
+    println!("wrote {} and {}", out, key);
+
+    return Ok(());
+}
+
+fn score_file(input: &str, questions: &Vec<Vec<(String, Choice, Choice)>>) -> Result<(AnswerSheet, (i8, i8, i8, i8), (i8, i8, i8, i8)), String> {
+
+    let text = std::fs::read_to_string(input).map_err(|e| format!("{}: {}", input, e))?;
+    return batch::score_answers(&text, questions).map_err(|e| format!("{}: {}", input, e));
+}
+
+/// The instrument named by `--form`, or the Standard form.
+fn questions(args: &[String]) -> Result<Vec<Vec<(String, Choice, Choice)>>, String> {
+
+    return match option(args, "--form") {
+        Some(path) => Ok(load_form(Path::new(&path))?.questions),
+        None => Ok(default_questions())
+    };
+}
+
+/// The profile library named by `--profiles`, or the one the window would load, so both score alike.
+fn profiles(args: &[String]) -> Result<Vec<Profile>, String> {
+
+    let path = option(args, "--profiles").unwrap_or("profiles.json".to_owned());
+
+    if option(args, "--profiles").is_none() && !Path::new(&path).exists() {
+        return Ok(default_profiles());
+    }
+
+    return load_library(Path::new(&path));
+}
+
+/// The highlights named by `--highlights`, or the ones the window would load.
+fn highlights(args: &[String]) -> Result<Vec<DimensionHighlights>, String> {
+
+    let path = option(args, "--highlights").unwrap_or("highlights.json".to_owned());
+
+    if option(args, "--highlights").is_none() && !Path::new(&path).exists() {
+        return Ok(default_highlights());
+    }
+
+    return load_highlights(Path::new(&path));
+}
+
+fn parse_graph_type(name: &str) -> Result<GraphType, String> {
+
+    match name.to_ascii_lowercase().as_str() {
+        "line" => Ok(GraphType::Line),
+        "bar" => Ok(GraphType::Bar),
+        "radar" => Ok(GraphType::Radar),
+        "circle" => Ok(GraphType::Circle),
+        _ => Err(format!("unknown graph type \"{}\"", name))
+    }
+}
+
+/// The first argument that is neither an option nor an option's value.
+fn positional(args: &[String]) -> Option<String> {
+
+    let mut skip = false;
+
+    for arg in args {
+
+        if skip { skip = false; continue; }
+        if arg.starts_with("--") { skip = true; continue; }
+
+        return Some(arg.to_owned());
+    }
+
+    return None;
+}
+
+fn option(args: &[String], name: &str) -> Option<String> {
+
+    let k = args.iter().position(|a| a == name)?;
+    return args.get(k + 1).cloned();
+}
@@ -0,0 +1,246 @@
+use std::sync::{Arc, Mutex};
+
+use serde_json::json;
+
+use crate::app::Choice;
+use crate::instrument::STANDARD_FORM;
+
+/// How often the facilitator's board asks the server for new submissions, in seconds.
+const POLL_INTERVAL: f64 = 2.0;
+
+#[derive(PartialEq, Clone, Copy, Debug)]
+pub(crate) enum Role { NONE, Facilitator, Participant }
+
+/// One person on the facilitator's board, as reported by `disc-server`.
+#[derive(serde::Deserialize, Clone, Debug)]
+pub(crate) struct BoardEntry {
+    pub(crate) name: String,
+    pub(crate) complete: bool,
+    pub(crate) result: Option<TeamResult>
+}
+
+#[derive(serde::Deserialize, Clone, Debug)]
+pub(crate) struct TeamResult {
+    pub(crate) intensity: Dimensions,
+    pub(crate) segments: Dimensions,
+    pub(crate) pattern: PatternName
+}
+
+#[derive(serde::Deserialize, Clone, Copy, Debug)]
+pub(crate) struct Dimensions {
+    #[serde(rename = "D")] pub(crate) d: i8,
+    #[serde(rename = "i")] pub(crate) i: i8,
+    #[serde(rename = "S")] pub(crate) s: i8,
+    #[serde(rename = "C")] pub(crate) c: i8
+}
+
+#[derive(serde::Deserialize, Clone, Debug)]
+pub(crate) struct PatternName {
+    pub(crate) name: String
+}
+
+#[derive(serde::Deserialize)]
+struct Board {
+    participants: Vec<BoardEntry>
+}
+
+/// State written by request callbacks, which finish on another thread natively.
+#[derive(Default)]
+pub(crate) struct Shared {
+    pub(crate) code: String,
+
+    /// Lets the facilitator read the board; participants never see it.
+    pub(crate) secret: String,
+
+    pub(crate) joined: bool,
+    pub(crate) submitted: bool,
+    pub(crate) board: Vec<BoardEntry>,
+    pub(crate) error: String
+}
+
+/// A team session shared through a locally run `disc-server`.
+#[derive(serde::Deserialize, serde::Serialize)]
+#[serde(default)]
+pub(crate) struct Collab {
+    pub(crate) server: String,
+
+    #[serde(skip)]
+    pub(crate) code: String,
+
+    #[serde(skip)]
+    pub(crate) name: String,
+
+    #[serde(skip)]
+    pub(crate) role: Role,
+
+    #[serde(skip)]
+    pub(crate) shared: Arc<Mutex<Shared>>,
+
+    #[serde(skip)]
+    last_poll: f64
+}
+
+impl Default for Collab {
+    fn default() -> Self {
+        Self {
+            server: "http://127.0.0.1:8080".to_owned(),
+            code: "".to_owned(),
+            name: "".to_owned(),
+            role: Role::NONE,
+            shared: Arc::new(Mutex::new(Shared::default())),
+            last_poll: f64::NEG_INFINITY
+        }
+    }
+}
+
+impl Collab {
+
+    /// Opens a new team session and starts showing its board.
+    pub(crate) fn start(&mut self, ctx: &egui::Context) {
+
+        self.reset(Role::Facilitator);
+
+        self.post("/teams", json!({}), ctx, |shared, value| {
+            shared.code = value["code"].as_str().unwrap_or_default().to_owned();
+            shared.secret = value["secret"].as_str().unwrap_or_default().to_owned();
+        });
+    }
+
+    pub(crate) fn join(&mut self, ctx: &egui::Context) {
+
+        self.reset(Role::Participant);
+        self.code = self.code.trim().to_ascii_uppercase();
+
+        let path = format!("/teams/{}/join", self.code);
+        self.post(&path, json!({ "name": self.name.trim() }), ctx, |shared, value| {
+            shared.code = value["code"].as_str().unwrap_or_default().to_owned();
+            shared.joined = true;
+        });
+    }
+
+    pub(crate) fn leave(&mut self) {
+
+        self.reset(Role::NONE);
+    }
+
+    /// Sends a finished answer sheet to the facilitator, if this app has joined a team session.
+    /// `chosen` is the MOST and LEAST word picked in each group, as indexes into `questions`.
+    pub(crate) fn submit(&mut self, form: &str, questions: &Vec<Vec<(String, Choice, Choice)>>, chosen: &Vec<(Option<usize>, Option<usize>)>, ctx: &egui::Context) {
+
+        if self.role != Role::Participant || !self.shared.lock().unwrap().joined {
+            return;
+        }
+
+        // disc-server only knows the built-in words.
+        if form != STANDARD_FORM {
+            self.shared.lock().unwrap().error = format!("Team sessions score the {} form, but these answers are to {}", STANDARD_FORM, form);
+            return;
+        }
+
+        let words = match answer_words(questions, chosen) {
+            Some(words) => words,
+            None => { self.shared.lock().unwrap().error = "Answer every group before submitting".to_owned(); return; }
+        };
+
+        let code = self.shared.lock().unwrap().code.to_owned();
+        let body = json!({
+            "name": self.name.trim(),
+            "form": form,
+            "responses": words.iter().map(|w| json!({ "most": w.0, "least": w.1 })).collect::<Vec<_>>()
+        });
+
+        self.post(&format!("/teams/{}/submit", code), body, ctx, |shared, _value| {
+            shared.submitted = true;
+        });
+    }
+
+    /// Refreshes the facilitator's board every `POLL_INTERVAL` seconds while it is on screen.
+    pub(crate) fn poll(&mut self, ctx: &egui::Context) {
+
+        let (code, secret) = { let shared = self.shared.lock().unwrap(); (shared.code.to_owned(), shared.secret.to_owned()) };
+
+        if self.role != Role::Facilitator || code.is_empty() {
+            return;
+        }
+
+        let now = ctx.input(|i| i.time);
+        ctx.request_repaint_after(std::time::Duration::from_secs_f64(POLL_INTERVAL));
+
+        if now - self.last_poll < POLL_INTERVAL {
+            return;
+        }
+
+        self.last_poll = now;
+
+        let shared = self.shared.clone();
+        let repaint = ctx.clone();
+
+        let mut request = ehttp::Request::get(format!("{}/teams/{}", self.server.trim_end_matches('/'), code));
+        request.headers.insert("Authorization".to_owned(), format!("Bearer {}", secret));
+
+        ehttp::fetch(request, move |result| {
+
+            let mut shared = shared.lock().unwrap();
+
+            match result.map_err(|e| e.to_string()).and_then(|r| parse::<Board>(&r)) {
+                Ok(board) => { shared.board = board.participants; shared.error.clear(); },
+                Err(e) => shared.error = e
+            }
+
+            repaint.request_repaint();
+        });
+    }
+
+    fn reset(&mut self, role: Role) {
+
+        self.role = role;
+        self.last_poll = f64::NEG_INFINITY;
+
+        // Replacing the shared state also orphans any request still in flight.
+        self.shared = Arc::new(Mutex::new(Shared::default()));
+    }
+
+    fn post(&self, path: &str, body: serde_json::Value, ctx: &egui::Context, on_ok: impl 'static + Send + FnOnce(&mut Shared, serde_json::Value)) {
+
+        let shared = self.shared.clone();
+        let repaint = ctx.clone();
+
+        ehttp::fetch(ehttp::Request::post(format!("{}{}", self.server.trim_end_matches('/'), path), body.to_string().into_bytes()), move |result| {
+
+            let mut shared = shared.lock().unwrap();
+
+            match result.map_err(|e| e.to_string()).and_then(|r| parse::<serde_json::Value>(&r)) {
+                Ok(value) => { shared.error.clear(); on_ok(&mut shared, value); },
+                Err(e) => shared.error = e
+            }
+
+            repaint.request_repaint();
+        });
+    }
+}
+
+/// The body of a successful response, or the server's error message.
+fn parse<T: serde::de::DeserializeOwned>(response: &ehttp::Response) -> Result<T, String> {
+
+    let text = response.text().unwrap_or_default();
+
+    if !response.ok {
+
+        let message = serde_json::from_str::<serde_json::Value>(text).ok().and_then(|v| v["error"].as_str().map(|e| e.to_owned()));
+        return Err(message.unwrap_or(format!("{} {}", response.status, response.status_text)));
+    }
+
+    return serde_json::from_str(text).map_err(|e| e.to_string());
+}
+
+/// The MOST and LEAST word chosen in each group, or `None` while any group is unanswered.
+fn answer_words(questions: &Vec<Vec<(String, Choice, Choice)>>, chosen: &Vec<(Option<usize>, Option<usize>)>) -> Option<Vec<(String, String)>> {
+
+    let mut words = vec![];
+
+    for (group, pick) in questions.iter().zip(chosen) {
+        words.push((group.get(pick.0?)?.0.to_owned(), group.get(pick.1?)?.0.to_owned()));
+    }
+
+    return Some(words);
+}
@@ -0,0 +1,175 @@
+//! Anonymized exports of saved sessions for research partners.
+//!
+//! Names, reflections and typed-in settings never leave the program. Each person is identified only by a
+//! salted hash of their name, so their sessions can be linked within one export but not traced back.
+
+use std::path::Path;
+
+use chrono::{DateTime, Utc};
+use serde_json::{json, Value};
+use sha2::{Digest, Sha256};
+
+use crate::app::{Choice, Coarsen, FOCUS_PRESETS, intensity_to_segment};
+use crate::audit::ResponseEvent;
+use crate::batch::csv_line;
+use crate::instrument::STANDARD_FORM;
+use crate::psychometrics::{ItemRecord, chosen_words};
+
+/// Typed-in salts shorter than this, in bytes, are short enough to guess names from.
+pub(crate) const MIN_SALT_BYTES: usize = 16;
+
+/// One saved session, as the export needs it.
+pub(crate) struct DatasetRecord<'a> {
+    pub(crate) name: &'a str,
+    pub(crate) date: Option<DateTime<Utc>>,
+    pub(crate) focus: &'a str,
+    pub(crate) form: &'a str,
+    pub(crate) seconds: Option<f64>,
+    pub(crate) responses: &'a Vec<(Choice, Choice)>,
+    pub(crate) events: &'a Vec<ResponseEvent>,
+    pub(crate) tally: (i8, i8, i8, i8),
+    pub(crate) intensity: (i8, i8, i8, i8),
+    pub(crate) pattern: String
+}
+
+/// A column of the export: (name, type, description).
+type Column = (String, &'static str, String);
+
+/// Writes the records to `path` as CSV, or as JSON when the path ends in `.json`. CSV exports also get a
+/// data dictionary next to them, named `<stem>_dictionary.csv`; JSON exports carry theirs inside.
+pub(crate) fn write_dataset(path: &Path, questions: &Vec<Vec<(String, Choice, Choice)>>, records: &[DatasetRecord<'_>], salt: &[u8], coarsen: Coarsen) -> Result<(), String> {
+
+    let columns = dictionary(questions, coarsen);
+
+    let mut ordered: Vec<&DatasetRecord<'_>> = records.iter().collect();
+    ordered.sort_by_key(|r| r.date);
+
+    let rows: Vec<Vec<Value>> = ordered.iter().enumerate().map(|(k, r)| row(k + 1, r, questions, salt, coarsen)).collect();
+
+    if path.extension().map_or(false, |e| e.eq_ignore_ascii_case("json")) {
+
+        let document = json!({
+            "dictionary": columns.iter().map(|c| json!({ "name": c.0, "type": c.1, "description": c.2 })).collect::<Vec<Value>>(),
+            "records": rows.iter().map(|r| Value::Object(columns.iter().map(|c| c.0.to_owned()).zip(r.iter().cloned()).collect())).collect::<Vec<Value>>()
+        });
+
+        let text = serde_json::to_string_pretty(&document).map_err(|e| e.to_string())?;
+        return std::fs::write(path, text).map_err(|e| format!("{}: {}", path.display(), e));
+    }
+
+    let mut csv = csv_line(columns.iter().map(|c| c.0.to_owned()));
+    for r in &rows { csv += &csv_line(r.iter().map(cell)); }
+
+    std::fs::write(path, csv).map_err(|e| format!("{}: {}", path.display(), e))?;
+
+    let stem = path.file_stem().map(|s| s.to_string_lossy().into_owned()).unwrap_or("dataset".to_owned());
+    let dictionary_path = path.with_file_name(format!("{}_dictionary.csv", stem));
+
+    let mut text = csv_line(["variable", "type", "description"].iter().map(|s| s.to_string()));
+    for c in &columns { text += &csv_line([c.0.to_owned(), c.1.to_owned(), c.2.to_owned()].into_iter()); }
+
+    return std::fs::write(&dictionary_path, text).map_err(|e| format!("{}: {}", dictionary_path.display(), e));
+}
+
+/// A fresh random salt, for exports that should not link to any other export.
+pub(crate) fn random_salt() -> Result<Vec<u8>, String> {
+
+    let mut salt = vec![0u8; 16];
+    getrandom::getrandom(&mut salt).map_err(|e| e.to_string())?;
+
+    return Ok(salt);
+}
+
+fn dictionary(questions: &Vec<Vec<(String, Choice, Choice)>>, coarsen: Coarsen) -> Vec<Column> {
+
+    let mut columns: Vec<Column> = vec![
+        ("participant".to_owned(), "text", "Salted SHA-256 hash of the participant's name, first 16 hex digits. Matches across exports only when they share a salt.".to_owned()),
+        ("session".to_owned(), "integer", "Row number, in order of completion.".to_owned())
+    ];
+
+    let date = match coarsen {
+        Coarsen::Exact => Some("Completion time, UTC, RFC 3339."),
+        Coarsen::Day => Some("Completion date, UTC, YYYY-MM-DD."),
+        Coarsen::Month => Some("Completion month, UTC, YYYY-MM."),
+        Coarsen::Omit => None
+    };
+
+    if let Some(description) = date {
+        columns.push(("completed".to_owned(), "text", description.to_owned() + " Blank when unknown."));
+    }
+
+    columns.push(("focus".to_owned(), "text", format!("Setting the answers describe: {}, or Other for one typed in.", FOCUS_PRESETS.join(", "))));
+    columns.push(("form".to_owned(), "text", "Instrument form answered.".to_owned()));
+    columns.push(("seconds".to_owned(), "integer", "Whole seconds from the answer sheet opening to the last answer. Blank when not timed.".to_owned()));
+
+    for d in ["D", "i", "S", "C"] { columns.push((format!("tally_{}", d), "integer", format!("{} tally: MOST answers minus LEAST answers on the {} scale.", d, d))); }
+    for d in ["D", "i", "S", "C"] { columns.push((format!("intensity_{}", d), "integer", format!("{} intensity, 1 to 28.", d))); }
+    for d in ["D", "i", "S", "C"] { columns.push((format!("segment_{}", d), "integer", format!("{} segment, 1 to 7.", d))); }
+
+    columns.push(("pattern".to_owned(), "text", "Classical profile pattern.".to_owned()));
+
+    for (g, group) in questions.iter().enumerate() {
+
+        let words = group.iter().map(|q| q.0.to_owned()).collect::<Vec<String>>().join(", ");
+
+        columns.push((format!("g{}_most", g + 1), "text", format!("Word chosen as MOST in group {} ({}). Blank when unknown.", g + 1, words)));
+        columns.push((format!("g{}_least", g + 1), "text", format!("Word chosen as LEAST in group {} ({}). Blank when unknown.", g + 1, words)));
+    }
+
+    return columns;
+}
+
+fn row(session: usize, r: &DatasetRecord<'_>, questions: &Vec<Vec<(String, Choice, Choice)>>, salt: &[u8], coarsen: Coarsen) -> Vec<Value> {
+
+    let mut cells = vec![json!(participant_id(r.name, salt)), json!(session)];
+
+    let format = match coarsen {
+        Coarsen::Exact => Some("%Y-%m-%dT%H:%M:%SZ"),
+        Coarsen::Day => Some("%Y-%m-%d"),
+        Coarsen::Month => Some("%Y-%m"),
+        Coarsen::Omit => None
+    };
+
+    if let Some(format) = format {
+        cells.push(json!(r.date.map(|d| d.format(format).to_string())));
+    }
+
+    cells.push(json!(if FOCUS_PRESETS.contains(&r.focus) { r.focus } else if r.focus.is_empty() { "" } else { "Other" }));
+    cells.push(json!(if r.form.is_empty() { STANDARD_FORM } else { r.form }));
+    cells.push(json!(r.seconds.map(|s| s.round() as i64)));
+
+    let tally = [r.tally.0, r.tally.1, r.tally.2, r.tally.3];
+    let intensity = [r.intensity.0, r.intensity.1, r.intensity.2, r.intensity.3];
+
+    cells.extend(tally.iter().map(|v| json!(v)));
+    cells.extend(intensity.iter().map(|v| json!(v)));
+    cells.extend(intensity.iter().map(|v| json!(intensity_to_segment(*v))));
+    cells.push(json!(r.pattern));
+
+    let chosen = chosen_words(questions, &ItemRecord { responses: r.responses, events: r.events });
+
+    for (group, (most, least)) in questions.iter().zip(chosen) {
+        cells.push(json!(most.map(|w| group[w].0.to_owned())));
+        cells.push(json!(least.map(|w| group[w].0.to_owned())));
+    }
+
+    return cells;
+}
+
+fn participant_id(name: &str, salt: &[u8]) -> String {
+
+    let mut hasher = Sha256::new();
+    hasher.update(salt);
+    hasher.update(name.trim().to_lowercase().as_bytes());
+
+    return hasher.finalize().iter().take(8).map(|b| format!("{:02x}", b)).collect();
+}
+
+fn cell(value: &Value) -> String {
+
+    return match value {
+        Value::Null => "".to_owned(),
+        Value::String(s) => s.to_owned(),
+        v => v.to_string()
+    };
+}
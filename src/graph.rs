@@ -0,0 +1,405 @@
+use std::path::Path;
+
+use std::f64::consts::PI;
+
+use egui::{Color32, Pos2, Vec2, Rect, Align2, FontId, Stroke, pos2, vec2, epaint::{self, ClippedShape, Primitive, Shape, TessellationOptions, ImageData, text::{Fonts, FontDefinitions}}};
+
+use crate::app::{GraphType, intensity_to_segment};
+
+// Size of the exported line and bar graphs in points, the same 0.75 aspect as the on-screen plot.
+// The radar chart and DiSC map are square.
+const GRAPH_WIDTH: f32 = 450.0;
+const GRAPH_HEIGHT: f32 = 600.0;
+
+/// Direction of each dimension on the radar chart and DiSC map, D top left then clockwise.
+pub(crate) const MAP_ANGLES: [f64; 4] = [0.75 * PI, 0.25 * PI, -0.25 * PI, -0.75 * PI];
+
+const MARGIN_LEFT: f32 = 45.0;
+const MARGIN_RIGHT: f32 = 30.0;
+const MARGIN_TOP: f32 = 25.0;
+const MARGIN_BOTTOM: f32 = 40.0;
+
+const ACCENT: Color32 = Color32::from_rgb(137, 207, 240);
+const TEXT: Color32 = Color32::from_rgb(60, 60, 60);
+
+/// A drawing primitive in graph space (points, origin top left) that can be written out as SVG or rasterized.
+#[derive(Clone, Debug)]
+pub(crate) enum Mark {
+    Line { points: Vec<Pos2>, width: f32, color: Color32 },
+    Rect { rect: Rect, fill: Color32 },
+    Circle { center: Pos2, radius: f32, fill: Color32 },
+    Polygon { points: Vec<Pos2>, fill: Color32, stroke: Color32 },
+    Text { pos: Pos2, text: String, size: f32, color: Color32, anchor: Align2 }
+}
+
+/// Marks on a white canvas of `size` points.
+#[derive(Clone, Debug)]
+pub(crate) struct Drawing {
+    pub(crate) size: Vec2,
+    pub(crate) marks: Vec<Mark>
+}
+
+/// Lays out the intensity graph the same way the Results page draws it.
+pub(crate) fn layout(graphType: GraphType, intensity: (i8, i8, i8, i8)) -> Drawing {
+
+    match graphType {
+        GraphType::Line | GraphType::Bar => layout_intensity_graph(graphType, intensity),
+        GraphType::Radar => layout_radar(intensity),
+        GraphType::Circle => layout_map(intensity)
+    }
+}
+
+fn layout_intensity_graph(graphType: GraphType, intensity: (i8, i8, i8, i8)) -> Drawing {
+
+    let mut marks = vec![Mark::Rect { rect: Rect::from_min_max(pos2(0.0, 0.0), pos2(GRAPH_WIDTH, GRAPH_HEIGHT)), fill: Color32::WHITE }];
+
+    let area = Rect::from_min_max(pos2(MARGIN_LEFT, MARGIN_TOP), pos2(GRAPH_WIDTH - MARGIN_RIGHT, GRAPH_HEIGHT - MARGIN_BOTTOM));
+    let to_screen = |x: f64, y: f64| pos2(area.left() + ((x - 0.5) / 4.0) as f32 * area.width(), area.bottom() - (y / 28.0) as f32 * area.height());
+
+    // Shade every other segment and number the segments down the right hand side.
+    for seg in 1..=7 {
+
+        let low = (seg as f64 - 1.0) * 4.0 + 0.5;
+        let high = (seg as f64 * 4.0 + 0.5).min(28.0);
+
+        if seg % 2 == 0 {
+            marks.push(Mark::Rect { rect: Rect::from_two_pos(to_screen(0.5, low), to_screen(4.5, high)), fill: Color32::from_rgba_unmultiplied(128, 128, 128, 32) });
+        }
+
+        marks.push(Mark::Text { pos: to_screen(4.5, (low + high) / 2.0) + egui::vec2(8.0, 0.0), text: seg.to_string(), size: 10.0, color: Color32::GRAY, anchor: Align2::LEFT_CENTER });
+    }
+
+    // Gridlines for each intensity, heavier at the start of each segment.
+    for y in 1..=28 {
+
+        let major = (y - 1) % 4 == 0;
+
+        marks.push(Mark::Line { points: vec![to_screen(0.5, y as f64), to_screen(4.5, y as f64)], width: if major { 1.0 } else { 0.5 }, color: Color32::from_gray(if major { 180 } else { 220 }) });
+
+        if major || y == 28 {
+            marks.push(Mark::Text { pos: to_screen(0.5, y as f64) - egui::vec2(8.0, 0.0), text: y.to_string(), size: 10.0, color: TEXT, anchor: Align2::RIGHT_CENTER });
+        }
+    }
+
+    // Dashed midline.
+    let mut x = 0.5;
+    while x < 4.5 {
+        marks.push(Mark::Line { points: vec![to_screen(x, 14.5), to_screen((x + 0.05).min(4.5), 14.5)], width: 1.0, color: Color32::GRAY });
+        x += 0.1;
+    }
+
+    let values = [intensity.0, intensity.1, intensity.2, intensity.3];
+
+    for (d, name) in ["D", "i", "S", "C"].iter().enumerate() {
+        marks.push(Mark::Text { pos: to_screen(d as f64 + 1.0, 0.0) + egui::vec2(0.0, 10.0), text: name.to_string(), size: 16.0, color: TEXT, anchor: Align2::CENTER_TOP });
+    }
+
+    match graphType {
+        GraphType::Line => {
+
+            let points: Vec<Pos2> = values.iter().enumerate().map(|(d, v)| to_screen(d as f64 + 1.0, *v as f64)).collect();
+
+            marks.push(Mark::Line { points: points.clone(), width: 2.0, color: ACCENT });
+
+            for p in points {
+                marks.push(Mark::Circle { center: p, radius: 4.0, fill: ACCENT });
+            }
+        },
+        GraphType::Bar => {
+
+            for (d, v) in values.iter().enumerate() {
+                marks.push(Mark::Rect { rect: Rect::from_two_pos(to_screen(d as f64 + 0.75, 0.0), to_screen(d as f64 + 1.25, *v as f64)), fill: ACCENT });
+            }
+        },
+        // Laid out by `layout_radar` and `layout_map`.
+        GraphType::Radar | GraphType::Circle => ()
+    }
+
+    for (d, v) in values.iter().enumerate() {
+        marks.push(Mark::Text { pos: to_screen(d as f64 + 1.0, *v as f64) - egui::vec2(0.0, 8.0), text: format!("{} ({})", v, intensity_to_segment(*v)), size: 11.0, color: TEXT, anchor: Align2::CENTER_BOTTOM });
+    }
+
+    return Drawing { size: vec2(GRAPH_WIDTH, GRAPH_HEIGHT), marks };
+}
+
+fn layout_radar(intensity: (i8, i8, i8, i8)) -> Drawing {
+
+    let (mut marks, center, radius) = polar_canvas();
+    let to_screen = |p: [f64; 2]| center + vec2(p[0] as f32, -p[1] as f32) * radius;
+
+    // One ring per segment boundary, heavier at the midline.
+    for seg in 1..=7 {
+        marks.push(Mark::Line { points: ring(center, radius * seg as f32 * 4.0 / 28.0), width: if seg == 4 { 1.0 } else { 0.5 }, color: Color32::from_gray(if seg == 4 { 160 } else { 200 }) });
+    }
+
+    for (d, name) in ["D", "i", "S", "C"].iter().enumerate() {
+
+        marks.push(Mark::Line { points: vec![center, to_screen(radar_point(d, 28.0))], width: 1.0, color: Color32::from_gray(180) });
+        marks.push(Mark::Text { pos: to_screen(radar_point(d, 31.0)), text: name.to_string(), size: 16.0, color: TEXT, anchor: Align2::CENTER_CENTER });
+    }
+
+    let values = [intensity.0, intensity.1, intensity.2, intensity.3];
+    let points: Vec<Pos2> = values.iter().enumerate().map(|(d, v)| to_screen(radar_point(d, *v as f64))).collect();
+
+    marks.push(Mark::Polygon { points: points.clone(), fill: Color32::from_rgba_unmultiplied(137, 207, 240, 120), stroke: ACCENT });
+
+    for (d, p) in points.iter().enumerate() {
+
+        marks.push(Mark::Circle { center: *p, radius: 4.0, fill: ACCENT });
+        marks.push(Mark::Text { pos: *p + vec2(0.0, -8.0), text: format!("{} ({})", values[d], intensity_to_segment(values[d])), size: 11.0, color: TEXT, anchor: Align2::CENTER_BOTTOM });
+    }
+
+    return Drawing { size: vec2(GRAPH_WIDTH, GRAPH_WIDTH), marks };
+}
+
+fn layout_map(intensity: (i8, i8, i8, i8)) -> Drawing {
+
+    let (mut marks, center, radius) = polar_canvas();
+
+    marks.push(Mark::Line { points: ring(center, radius), width: 1.5, color: Color32::from_gray(160) });
+    marks.push(Mark::Line { points: ring(center, radius * 0.5), width: 0.5, color: Color32::from_gray(200) });
+    marks.push(Mark::Line { points: vec![center - vec2(radius, 0.0), center + vec2(radius, 0.0)], width: 1.0, color: Color32::from_gray(180) });
+    marks.push(Mark::Line { points: vec![center - vec2(0.0, radius), center + vec2(0.0, radius)], width: 1.0, color: Color32::from_gray(180) });
+
+    for (d, name) in ["D", "i", "S", "C"].iter().enumerate() {
+
+        let p = center + vec2(MAP_ANGLES[d].cos() as f32, -MAP_ANGLES[d].sin() as f32) * radius * 0.75;
+        marks.push(Mark::Text { pos: p, text: name.to_string(), size: 28.0, color: Color32::from_gray(190), anchor: Align2::CENTER_CENTER });
+    }
+
+    let (angle, distance) = map_placement([intensity.0 as f64, intensity.1 as f64, intensity.2 as f64, intensity.3 as f64]);
+    let dot = center + vec2(angle.cos() as f32, -angle.sin() as f32) * (distance as f32) * radius;
+
+    marks.push(Mark::Circle { center: dot, radius: 7.0, fill: ACCENT });
+    marks.push(Mark::Text { pos: pos2(center.x, GRAPH_WIDTH - 12.0), text: format!("angle {:.0}°, distance {:.2}", angle.to_degrees().rem_euclid(360.0), distance), size: 11.0, color: TEXT, anchor: Align2::CENTER_BOTTOM });
+
+    return Drawing { size: vec2(GRAPH_WIDTH, GRAPH_WIDTH), marks };
+}
+
+/// A white square canvas with the centre and radius available to a round chart.
+fn polar_canvas() -> (Vec<Mark>, Pos2, f32) {
+
+    let marks = vec![Mark::Rect { rect: Rect::from_min_max(pos2(0.0, 0.0), pos2(GRAPH_WIDTH, GRAPH_WIDTH)), fill: Color32::WHITE }];
+    return (marks, pos2(GRAPH_WIDTH / 2.0, GRAPH_WIDTH / 2.0), GRAPH_WIDTH / 2.0 - 50.0);
+}
+
+fn ring(center: Pos2, radius: f32) -> Vec<Pos2> {
+
+    return (0..=96).map(|k| center + vec2((k as f32 / 96.0 * std::f32::consts::TAU).cos(), (k as f32 / 96.0 * std::f32::consts::TAU).sin()) * radius).collect();
+}
+
+/// Position of intensity `value` on dimension `d`'s spoke of the radar chart, where 28 reaches the unit circle.
+pub(crate) fn radar_point(d: usize, value: f64) -> [f64; 2] {
+
+    return [MAP_ANGLES[d].cos() * value / 28.0, MAP_ANGLES[d].sin() * value / 28.0];
+}
+
+/// Where the respondent sits on the DiSC map as an angle (radians, counterclockwise from the right) and a distance from the
+/// centre between 0 and 1. Each dimension pulls towards its own quadrant by how far its intensity is above or below the midline.
+pub(crate) fn map_placement(intensity: [f64; 4]) -> (f64, f64) {
+
+    let mut x = 0.0;
+    let mut y = 0.0;
+
+    for d in 0..4 {
+
+        let pull = (intensity[d] - 14.5) / 13.5;
+
+        x += pull * MAP_ANGLES[d].cos();
+        y += pull * MAP_ANGLES[d].sin();
+    }
+
+    // Two dimensions at the top and two at the bottom of the scale give the longest possible pull, 2 * sqrt(2).
+    return (y.atan2(x), ((x * x + y * y).sqrt() / (2.0 * 2.0_f64.sqrt())).min(1.0));
+}
+
+/// Writes the graph as SVG or PNG depending on the file extension.
+pub(crate) fn write(path: &Path, graphType: GraphType, intensity: (i8, i8, i8, i8), width: u32) -> Result<(), String> {
+
+    let drawing = layout(graphType, intensity);
+
+    let bytes = match path.extension().and_then(|e| e.to_str()).map(|e| e.to_ascii_lowercase()).as_deref() {
+        Some("png") => to_png(&drawing, width)?,
+        Some("svg") => to_svg(&drawing).into_bytes(),
+        _ => return Err(format!("{}: the graph can only be exported as .svg or .png", path.display()))
+    };
+
+    return std::fs::write(path, bytes).map_err(|e| format!("{}: {}", path.display(), e));
+}
+
+pub(crate) fn to_svg(drawing: &Drawing) -> String {
+
+    let mut svg = format!("<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{w}\" height=\"{h}\" viewBox=\"0 0 {w} {h}\" font-family=\"sans-serif\">\n", w = drawing.size.x, h = drawing.size.y);
+
+    for mark in &drawing.marks {
+
+        match mark {
+            Mark::Line { points, width, color } => {
+
+                let points: Vec<String> = points.iter().map(|p| format!("{:.2},{:.2}", p.x, p.y)).collect();
+                svg += &format!("  <polyline points=\"{}\" fill=\"none\" stroke-width=\"{}\" {}/>\n", points.join(" "), width, svg_paint("stroke", *color));
+            },
+            Mark::Rect { rect, fill } => {
+                svg += &format!("  <rect x=\"{:.2}\" y=\"{:.2}\" width=\"{:.2}\" height=\"{:.2}\" {}/>\n", rect.left(), rect.top(), rect.width(), rect.height(), svg_paint("fill", *fill));
+            },
+            Mark::Circle { center, radius, fill } => {
+                svg += &format!("  <circle cx=\"{:.2}\" cy=\"{:.2}\" r=\"{}\" {}/>\n", center.x, center.y, radius, svg_paint("fill", *fill));
+            },
+            Mark::Polygon { points, fill, stroke } => {
+
+                let points: Vec<String> = points.iter().map(|p| format!("{:.2},{:.2}", p.x, p.y)).collect();
+                svg += &format!("  <polygon points=\"{}\" stroke-width=\"2\" {} {}/>\n", points.join(" "), svg_paint("fill", *fill), svg_paint("stroke", *stroke));
+            },
+            Mark::Text { pos, text, size, color, anchor } => {
+
+                let horizontal = match anchor.x() { egui::Align::Min => "start", egui::Align::Center => "middle", egui::Align::Max => "end" };
+                let vertical = match anchor.y() { egui::Align::Min => "hanging", egui::Align::Center => "central", egui::Align::Max => "alphabetic" };
+
+                svg += &format!("  <text x=\"{:.2}\" y=\"{:.2}\" font-size=\"{}\" text-anchor=\"{}\" dominant-baseline=\"{}\" {}>{}</text>\n", pos.x, pos.y, size, horizontal, vertical, svg_paint("fill", *color), escape_xml(text));
+            }
+        }
+    }
+
+    svg += "</svg>\n";
+    return svg;
+}
+
+/// Renders the drawing to an RGBA PNG `width` pixels wide, using egui's own fonts and tessellator.
+pub(crate) fn to_png(drawing: &Drawing, width: u32) -> Result<Vec<u8>, String> {
+
+    let pixels_per_point = width as f32 / drawing.size.x;
+    let size = [width as usize, (drawing.size.y * pixels_per_point).round() as usize];
+
+    let fonts = Fonts::new(pixels_per_point, 8192, FontDefinitions::default());
+
+    let shapes: Vec<ClippedShape> = drawing.marks.iter().map(|mark| {
+
+        let shape = match mark {
+            Mark::Line { points, width, color } => Shape::line(points.clone(), Stroke::new(*width, *color)),
+            Mark::Rect { rect, fill } => Shape::rect_filled(*rect, 0.0, *fill),
+            Mark::Circle { center, radius, fill } => Shape::circle_filled(*center, *radius, *fill),
+            Mark::Polygon { points, fill, stroke } => Shape::convex_polygon(points.clone(), *fill, Stroke::new(2.0, *stroke)),
+            Mark::Text { pos, text, size, color, anchor } => Shape::text(&fonts, *pos, *anchor, text, FontId::proportional(*size), *color)
+        };
+
+        return ClippedShape(Rect::EVERYTHING, shape);
+    }).collect();
+
+    let font_image = match fonts.font_image_delta().map(|delta| delta.image) {
+        Some(ImageData::Font(image)) => image,
+        _ => return Err("The font atlas could not be built.".to_owned())
+    };
+    let texels: Vec<Color32> = font_image.srgba_pixels(None).collect();
+
+    let atlas = fonts.texture_atlas();
+    let prepared_discs = atlas.lock().prepared_discs();
+    let primitives = epaint::tessellator::tessellate_shapes(pixels_per_point, TessellationOptions::default(), font_image.size, prepared_discs, shapes);
+
+    let mut canvas = vec![Color32::TRANSPARENT; size[0] * size[1]];
+
+    for primitive in primitives {
+
+        if let Primitive::Mesh(mesh) = primitive.primitive {
+
+            for triangle in mesh.indices.chunks_exact(3) {
+
+                let v = [&mesh.vertices[triangle[0] as usize], &mesh.vertices[triangle[1] as usize], &mesh.vertices[triangle[2] as usize]];
+                rasterize_triangle(&mut canvas, size, pixels_per_point, v, &texels, font_image.size);
+            }
+        }
+    }
+
+    let data: Vec<u8> = canvas.iter().flat_map(|c| c.to_srgba_unmultiplied()).collect();
+    let mut bytes = vec![];
+
+    {
+        let mut encoder = png::Encoder::new(&mut bytes, size[0] as u32, size[1] as u32);
+        encoder.set_color(png::ColorType::Rgba);
+        encoder.set_depth(png::BitDepth::Eight);
+
+        let mut writer = encoder.write_header().map_err(|e| e.to_string())?;
+        writer.write_image_data(&data).map_err(|e| e.to_string())?;
+    }
+
+    return Ok(bytes);
+}
+
+fn rasterize_triangle(canvas: &mut [Color32], size: [usize; 2], pixels_per_point: f32, v: [&epaint::Vertex; 3], texels: &[Color32], texture_size: [usize; 2]) {
+
+    let mut v = v;
+    let mut p: Vec<Pos2> = v.iter().map(|v| pos2(v.pos.x * pixels_per_point, v.pos.y * pixels_per_point)).collect();
+
+    let mut area = (p[1].x - p[0].x) * (p[2].y - p[0].y) - (p[2].x - p[0].x) * (p[1].y - p[0].y);
+
+    if area.abs() < f32::EPSILON {
+        return;
+    }
+
+    // Wind every triangle the same way so an edge shared by two triangles runs in opposite directions in each.
+    if area < 0.0 {
+        v.swap(1, 2);
+        p.swap(1, 2);
+        area = -area;
+    }
+
+    // Pixels exactly on an edge belong to only one of the two triangles sharing it, otherwise they are blended twice.
+    let owns_edge = |a: Pos2, b: Pos2| b.y > a.y || (b.y == a.y && b.x < a.x);
+    let inside = |w: f32, a: Pos2, b: Pos2| w > 0.0 || (w == 0.0 && owns_edge(a, b));
+
+    let min_x = p.iter().map(|p| p.x).fold(f32::INFINITY, f32::min).floor().max(0.0) as usize;
+    let max_x = p.iter().map(|p| p.x).fold(f32::NEG_INFINITY, f32::max).ceil().min(size[0] as f32) as usize;
+    let min_y = p.iter().map(|p| p.y).fold(f32::INFINITY, f32::min).floor().max(0.0) as usize;
+    let max_y = p.iter().map(|p| p.y).fold(f32::NEG_INFINITY, f32::max).ceil().min(size[1] as f32) as usize;
+
+    for y in min_y..max_y {
+        for x in min_x..max_x {
+
+            // Sample at the pixel centre, using barycentric weights to interpolate colour and uv.
+            let c = pos2(x as f32 + 0.5, y as f32 + 0.5);
+
+            let w0 = ((p[1].x - c.x) * (p[2].y - c.y) - (p[2].x - c.x) * (p[1].y - c.y)) / area;
+            let w1 = ((p[2].x - c.x) * (p[0].y - c.y) - (p[0].x - c.x) * (p[2].y - c.y)) / area;
+            let w2 = 1.0 - w0 - w1;
+
+            if !inside(w0, p[1], p[2]) || !inside(w1, p[2], p[0]) || !inside(w2, p[0], p[1]) {
+                continue;
+            }
+
+            let u = w0 * v[0].uv.x + w1 * v[1].uv.x + w2 * v[2].uv.x;
+            let t = w0 * v[0].uv.y + w1 * v[1].uv.y + w2 * v[2].uv.y;
+
+            let tx = ((u * texture_size[0] as f32) as usize).min(texture_size[0] - 1);
+            let ty = ((t * texture_size[1] as f32) as usize).min(texture_size[1] - 1);
+            let texel = texels[ty * texture_size[0] + tx];
+
+            // Colours are premultiplied, so "over" blending is src + dst * (1 - src alpha).
+            let channel = |k: usize| (w0 * v[0].color[k] as f32 + w1 * v[1].color[k] as f32 + w2 * v[2].color[k] as f32) * texel[k] as f32 / 255.0;
+            let src = [channel(0), channel(1), channel(2), channel(3)];
+
+            let dst = &mut canvas[y * size[0] + x];
+            let keep = 1.0 - src[3] / 255.0;
+
+            *dst = Color32::from_rgba_premultiplied(
+                (src[0] + dst[0] as f32 * keep).round().min(255.0) as u8,
+                (src[1] + dst[1] as f32 * keep).round().min(255.0) as u8,
+                (src[2] + dst[2] as f32 * keep).round().min(255.0) as u8,
+                (src[3] + dst[3] as f32 * keep).round().min(255.0) as u8);
+        }
+    }
+}
+
+fn svg_paint(attribute: &str, color: Color32) -> String {
+
+    let [r, g, b, a] = color.to_srgba_unmultiplied();
+
+    if a == 255 {
+        return format!("{}=\"rgb({},{},{})\"", attribute, r, g, b);
+    }
+
+    return format!("{}=\"rgb({},{},{})\" {}-opacity=\"{:.3}\"", attribute, r, g, b, attribute, a as f32 / 255.0);
+}
+
+pub(crate) fn escape_xml(text: &str) -> String {
+
+    return text.replace('&', "&amp;").replace('<', "&lt;").replace('>', "&gt;").replace('"', "&quot;");
+}
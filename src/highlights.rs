@@ -0,0 +1,261 @@
+use std::path::Path;
+
+/// The description shown for one of the D, i, S and C dimensions.
+#[derive(serde::Deserialize, serde::Serialize, PartialEq, Clone, Debug)]
+pub(crate) struct DimensionHighlights {
+    pub(crate) title: String,
+    pub(crate) emphasis: String,
+    pub(crate) tendencies: Vec<String>,
+    pub(crate) environment: Vec<String>,
+    pub(crate) needs_others_who: Vec<String>,
+    pub(crate) more_effective: Vec<String>,
+
+    /// Text that depends on how strong the dimension is, chosen by segment.
+    #[serde(default)]
+    pub(crate) narratives: Vec<SegmentNarrative>,
+
+    /// Adjectives for each segment, from segment 1 to 7, as on the paper "Segment Numbers" chart.
+    #[serde(default)]
+    pub(crate) descriptors: Vec<Vec<String>>
+}
+
+/// Describes a dimension when its segment is between `low` and `high`, inclusive.
+#[derive(serde::Deserialize, serde::Serialize, PartialEq, Clone, Debug)]
+pub(crate) struct SegmentNarrative {
+    pub(crate) low: i8,
+    pub(crate) high: i8,
+    pub(crate) label: String,
+    pub(crate) text: String
+}
+
+/// Headings for the four lists, in the order they are shown.
+pub(crate) const HIGHLIGHT_HEADINGS: [&str; 4] = ["This person's tendencies include",
+                                                  "This person desires an environment that includes",
+                                                  "This person needs others who",
+                                                  "To be more effective, this person needs"];
+
+impl DimensionHighlights {
+
+    pub(crate) fn lists(&self) -> [&Vec<String>; 4] {
+
+        return [&self.tendencies, &self.environment, &self.needs_others_who, &self.more_effective];
+    }
+
+    /// The adjectives for a segment from 1 to 7.
+    pub(crate) fn descriptors_at(&self, segment: i8) -> &[String] {
+
+        return self.descriptors.get((segment - 1).max(0) as usize).map(|d| &d[..]).unwrap_or(&[]);
+    }
+
+    /// The narrative for a segment from 1 to 7, if one covers it.
+    pub(crate) fn narrative(&self, segment: i8) -> Option<&SegmentNarrative> {
+
+        return self.narratives.iter().find(|n| segment >= n.low && segment <= n.high);
+    }
+}
+
+/// Highlights for D, i, S and C, in that order.
+pub(crate) fn default_highlights() -> Vec<DimensionHighlights> {
+
+    return vec![
+        DimensionHighlights { title: "DOMINANCE".to_owned(),
+                              emphasis: "Emphasis is on shaping the environment by overcoming opposition to accomplish results.".to_owned(),
+                              tendencies: items(&["getting immediate results",
+                                                  "causing action",
+                                                  "accepting challanges",
+                                                  "making quick decisions",
+                                                  "questioning the status quo",
+                                                  "taking authority",
+                                                  "managing trouble",
+                                                  "solving problems"]),
+                              environment: items(&["power and authority",
+                                                   "prestige and challenge",
+                                                   "opportunities for individual accomplishments",
+                                                   "a wipe scrope of operations",
+                                                   "direct answers",
+                                                   "opportunities for advancement",
+                                                   "freedom from controls and supervision",
+                                                   "many new and varied activities"]),
+                              needs_others_who: items(&["weigh pros and cons",
+                                                        "calculate risks",
+                                                        "use caution",
+                                                        "create a predictable environment",
+                                                        "research facts",
+                                                        "deliberate before deciding",
+                                                        "recognize the needs of others"]),
+                              more_effective: items(&["to receive difficult assignments",
+                                                      "to understand that they need people",
+                                                      "to base techniques on practical experience",
+                                                      "to receive an occasional shock",
+                                                      "to identify with a group",
+                                                      "to verbalize reasons for conclusions",
+                                                      "to be aware of existing sanctions",
+                                                      "to pace self and to relax more"]),
+                              narratives: vec![narrative(1, 2, "Low D", "Mild, cooperative and unassuming. Prefers to avoid confrontation, looks for agreement before acting, and may hesitate to impose their will on others."),
+                                               narrative(3, 4, "Moderate D", "Assertive when the situation calls for it, but generally willing to work within the group's plans. Weighs results against relationships."),
+                                               narrative(5, 7, "High D", "Direct, decisive and competitive. Pushes for results quickly, takes charge when things stall, and challenges ideas or people that slow progress.")],
+                              descriptors: vec![items(&["peaceful", "modest", "unassuming"]),
+                                                items(&["mild", "agreeable", "cautious"]),
+                                                items(&["measured", "deliberate", "even-handed"]),
+                                                items(&["self-reliant", "frank", "purposeful"]),
+                                                items(&["assertive", "competitive", "self-assured"]),
+                                                items(&["decisive", "driving", "daring"]),
+                                                items(&["demanding", "forceful", "domineering"])] },
+
+        DimensionHighlights { title: "INFLUENCE".to_owned(),
+                              emphasis: "Emphasis is on shaping the environment by influencing or persuading others.".to_owned(),
+                              tendencies: items(&["contacting people",
+                                                  "making a favorable impression",
+                                                  "being articulate",
+                                                  "creating a motivating environment",
+                                                  "generating enthusiasm",
+                                                  "entertaining people",
+                                                  "viewing people and situations with optimism",
+                                                  "participating in a group"]),
+                              environment: items(&["popularity, social recognition",
+                                                   "public recognition of ability",
+                                                   "freedom of expression",
+                                                   "group activities outside of job",
+                                                   "democratic relationships",
+                                                   "freedom from control and detail",
+                                                   "opportunities to verbalize proposals",
+                                                   "coaching and counseling",
+                                                   "favorable working conditions"]),
+                              needs_others_who: items(&["concentrate on the task",
+                                                        "seek facts",
+                                                        "speak directly",
+                                                        "respect sincerity",
+                                                        "develop systematic approaches",
+                                                        "prefer to deal with things instead of people",
+                                                        "take a logical approach",
+                                                        "demonstrate individual follow-through"]),
+                              more_effective: items(&["to control time, if 'D' or 'S' is low",
+                                                      "to make objective decisions",
+                                                      "to use hands-on management",
+                                                      "to be more realistic when appraising others",
+                                                      "to make priorities and deadlines",
+                                                      "to be more firm with others, if D is low"]),
+                              narratives: vec![narrative(1, 2, "Low i", "Reflective, factual and reserved. Prefers to let results speak for themselves, is sceptical of hype, and may seem distant to people who don't know them."),
+                                               narrative(3, 4, "Moderate i", "Friendly and at ease with people, but balances socializing with attention to the work. Persuasive when the goal matters to them."),
+                                               narrative(5, 7, "High i", "Outgoing, persuasive and optimistic. Energized by people, quick to share ideas, and relies on enthusiasm and personal connection to move others.")],
+                              descriptors: vec![items(&["reflective", "withdrawn", "factual"]),
+                                                items(&["reserved", "sceptical", "matter-of-fact"]),
+                                                items(&["controlled", "discerning", "thoughtful"]),
+                                                items(&["sociable", "poised", "trusting"]),
+                                                items(&["persuasive", "warm", "optimistic"]),
+                                                items(&["enthusiastic", "inspiring", "expressive"]),
+                                                items(&["effusive", "gregarious", "impulsive"])] },
+
+        DimensionHighlights { title: "STEADINESS".to_owned(),
+                              emphasis: "Emphasis is on cooperating with others within existing circumstances to carry out the task.".to_owned(),
+                              tendencies: items(&["performing in a consistent, predictable manner",
+                                                  "demonstrating patience",
+                                                  "developing specialized skills",
+                                                  "helping others",
+                                                  "showing loyalty",
+                                                  "being a good listener",
+                                                  "calming excited people",
+                                                  "creating a stable harmonious work environment"]),
+                              environment: items(&["maintenance of the status quo unless given reasons for change",
+                                                   "predictable routines",
+                                                   "credit for work accomplished",
+                                                   "minimal work infringement on home life",
+                                                   "sincere appreciation",
+                                                   "identification with a group",
+                                                   "standard operating procedures",
+                                                   "minimal conflicts"]),
+                              needs_others_who: items(&["react quickly to unexpected change",
+                                                        "stretch toward the challenges of accepted tasks",
+                                                        "become involved in more than one thing",
+                                                        "are self-promoting",
+                                                        "apply pressure on others",
+                                                        "work comfortably in an unpredictable environment",
+                                                        "help to prioritize work",
+                                                        "are flexible in work procedures"]),
+                              more_effective: items(&["to be conditioned prior to change",
+                                                      "to validate self-worth",
+                                                      "to know how personal effort contributes to the group effort",
+                                                      "to have colleagues of similar competence and sincerity",
+                                                      "to know task guidelines",
+                                                      "to have creativity encouraged"]),
+                              narratives: vec![narrative(1, 2, "Low S", "Restless, active and eager for variety. Moves quickly between tasks, welcomes change, and may grow impatient with slow or repetitive work."),
+                                               narrative(3, 4, "Moderate S", "Adaptable. Comfortable with routine but able to change course when needed, and generally even-tempered under pressure."),
+                                               narrative(5, 7, "High S", "Patient, steady and loyal. Values stability, works at a consistent pace, listens well, and may resist sudden change unless its reasons are explained.")],
+                              descriptors: vec![items(&["restless", "impatient", "spontaneous"]),
+                                                items(&["active", "eager", "changeable"]),
+                                                items(&["alert", "outgoing", "flexible"]),
+                                                items(&["amiable", "relaxed", "steady"]),
+                                                items(&["patient", "dependable", "loyal"]),
+                                                items(&["calm", "deliberate", "stable"]),
+                                                items(&["passive", "possessive", "predictable"])] },
+
+        DimensionHighlights { title: "CONSCIENTIOUSNESS".to_owned(),
+                              emphasis: "Emphasis is on working conscientiously within existing circumstances to ensure quality and accuracy.".to_owned(),
+                              tendencies: items(&["adhering to key directives and standards",
+                                                  "concentratingon key details",
+                                                  "thinking analytically, weighing pros and cons",
+                                                  "being diplomatic with people",
+                                                  "using subtle or indirect approaches to conflict",
+                                                  "checking for accuracy",
+                                                  "analyzing performance critically",
+                                                  "using a systematic approach to situations or activities"]),
+                              environment: items(&["clearly defined performance expectations",
+                                                   "values of quality and accuracy",
+                                                   "a reserved, business-like atmosphere",
+                                                   "opportunities to demonstrate expertise",
+                                                   "control over factors that affect their performance",
+                                                   "opportunities to ask \"why\" questions",
+                                                   "recognition for specific skills and accomplishments"]),
+                              needs_others_who: items(&["delegate important tasks",
+                                                        "make quick decisions",
+                                                        "use policies only as guidelines",
+                                                        "compromise with the opposition",
+                                                        "state unpopular positions",
+                                                        "initiate and facilitate discussions",
+                                                        "encourage teamwork"]),
+                              more_effective: items(&["to have time to plan carefully",
+                                                      "to know exact job descriptions and performance objectives",
+                                                      "to schedule performance appraisals",
+                                                      "to receieve specific feedback on performance",
+                                                      "to respect people's personal worth as much as their accomplishments",
+                                                      "to develop tolerance for conflict"]),
+                              narratives: vec![narrative(1, 2, "Low C", "Independent and unconventional. Comfortable with ambiguity, prefers broad strokes to detail, and treats rules as guidelines rather than requirements."),
+                                               narrative(3, 4, "Moderate C", "Attends to quality and procedure where they matter, but is willing to bend them when the situation warrants it."),
+                                               narrative(5, 7, "High C", "Careful, precise and systematic. Holds high standards, wants the facts before deciding, and follows procedures closely to avoid mistakes.")],
+                              descriptors: vec![items(&["defiant", "careless", "uninhibited"]),
+                                                items(&["independent", "unconventional", "firm"]),
+                                                items(&["self-directed", "persistent", "opinionated"]),
+                                                items(&["diplomatic", "conscientious", "analytical"]),
+                                                items(&["careful", "precise", "systematic"]),
+                                                items(&["exacting", "perfectionist", "tactful"]),
+                                                items(&["meticulous", "rule-bound", "worrying"])] }];
+}
+
+pub(crate) fn load_highlights(path: &Path) -> Result<Vec<DimensionHighlights>, String> {
+
+    let text = std::fs::read_to_string(path).map_err(|e| format!("{}: {}", path.display(), e))?;
+    let highlights: Vec<DimensionHighlights> = serde_json::from_str(&text).map_err(|e| format!("{}: {}", path.display(), e))?;
+
+    if highlights.len() != 4 {
+        return Err(format!("{}: expected highlights for D, i, S and C but found {}", path.display(), highlights.len()));
+    }
+
+    return Ok(highlights);
+}
+
+pub(crate) fn save_highlights(highlights: &Vec<DimensionHighlights>, path: &Path) -> Result<(), String> {
+
+    let text = serde_json::to_string_pretty(highlights).map_err(|e| e.to_string())?;
+
+    return std::fs::write(path, text).map_err(|e| format!("{}: {}", path.display(), e));
+}
+
+fn narrative(low: i8, high: i8, label: &str, text: &str) -> SegmentNarrative {
+
+    return SegmentNarrative { low, high, label: label.to_owned(), text: text.to_owned() };
+}
+
+fn items(list: &[&str]) -> Vec<String> {
+
+    return list.iter().map(|s| s.to_string()).collect();
+}
@@ -0,0 +1,387 @@
+use std::path::Path;
+
+use crate::app::{Choice, default_questions};
+
+/// The name of the built-in instrument.
+pub(crate) const STANDARD_FORM: &str = "Standard";
+
+/// One version of the instrument: groups of (word, MOST scale, LEAST scale).
+#[derive(serde::Deserialize, serde::Serialize, PartialEq, Clone, Debug)]
+#[serde(try_from = "FormFile", into = "FormFile")]
+pub(crate) struct Form {
+    pub(crate) name: String,
+    pub(crate) questions: Vec<Vec<(String, Choice, Choice)>>
+}
+
+/// Instrument files spell scales out, so they can be written by hand:
+///
+/// ```json
+/// { "name": "Form B", "groups": [[{ "word": "daring", "most": "D", "least": "D" }, ...], ...] }
+/// ```
+#[derive(serde::Deserialize, serde::Serialize, Clone)]
+struct FormFile {
+    name: String,
+    groups: Vec<Vec<WordEntry>>
+}
+
+#[derive(serde::Deserialize, serde::Serialize, Clone)]
+struct WordEntry {
+    word: String,
+    most: String,
+    least: String
+}
+
+impl TryFrom<FormFile> for Form {
+    type Error = String;
+
+    fn try_from(file: FormFile) -> Result<Self, String> {
+
+        let mut questions = vec![];
+
+        for (g, group) in file.groups.iter().enumerate() {
+
+            let mut words = vec![];
+
+            for entry in group {
+                let most = parse_scale(&entry.most).ok_or(format!("group {}: \"{}\" has an unknown MOST scale \"{}\"", g + 1, entry.word, entry.most))?;
+                let least = parse_scale(&entry.least).ok_or(format!("group {}: \"{}\" has an unknown LEAST scale \"{}\"", g + 1, entry.word, entry.least))?;
+                words.push((entry.word.to_owned(), most, least));
+            }
+
+            questions.push(words);
+        }
+
+        return Ok(Form { name: file.name, questions });
+    }
+}
+
+impl From<Form> for FormFile {
+
+    fn from(form: Form) -> Self {
+
+        let groups = form.questions.iter()
+            .map(|group| group.iter().map(|q| WordEntry { word: q.0.to_owned(), most: scale_name(q.1).to_owned(), least: scale_name(q.2).to_owned() }).collect())
+            .collect();
+
+        return FormFile { name: form.name, groups };
+    }
+}
+
+pub(crate) fn standard_form() -> Form {
+
+    return Form { name: STANDARD_FORM.to_owned(), questions: default_questions() };
+}
+
+/// Reads an instrument file that is ready to be answered, refusing any form `validate` finds a problem with.
+pub(crate) fn load_form(path: &Path) -> Result<Form, String> {
+
+    let form = read_form(path)?;
+    let problems = validate(&form);
+
+    if !problems.is_empty() {
+        return Err(format!("{}: {}", path.display(), problems.join("; ")));
+    }
+
+    return Ok(form);
+}
+
+/// Reads an instrument file as it is, so the editor can open a form that still needs fixing.
+pub(crate) fn read_form(path: &Path) -> Result<Form, String> {
+
+    let text = std::fs::read_to_string(path).map_err(|e| format!("{}: {}", path.display(), e))?;
+
+    return serde_json::from_str(&text).map_err(|e| format!("{}: {}", path.display(), e));
+}
+
+/// Writes a form as an instrument file that `load_form` can read back.
+pub(crate) fn save_form(form: &Form, path: &Path) -> Result<(), String> {
+
+    let text = serde_json::to_string_pretty(form).map_err(|e| e.to_string())?;
+
+    return std::fs::write(path, text).map_err(|e| format!("{}: {}", path.display(), e));
+}
+
+/// Every key the editor offers, in order. `Choice::NONE` is left out: on the Response page it reads as
+/// an unanswered column, so only forms written by hand (such as the Standard form's "original") use it.
+pub(crate) const SCALES: [Choice; 5] = [Choice::A, Choice::B, Choice::C, Choice::D, Choice::E];
+
+/// What stops a form being used, one problem per line. An empty list means every group is balanced.
+///
+/// A group is balanced when no key appears twice in a column, so no word counts twice and every answer
+/// can be traced back to the word that was chosen. That includes E and no key at all: words sharing one
+/// of those would score alike but could not be told apart.
+pub(crate) fn validate(form: &Form) -> Vec<String> {
+
+    let mut problems = vec![];
+
+    if form.name.trim().is_empty() {
+        problems.push("The form needs a name".to_owned());
+    } else if form.name.trim() == STANDARD_FORM {
+        problems.push(format!("\"{}\" is the name of the built-in form", STANDARD_FORM));
+    }
+
+    if form.questions.is_empty() {
+        problems.push("The form needs at least one group".to_owned());
+    }
+
+    for (g, group) in form.questions.iter().enumerate() {
+
+        if group.len() < 2 {
+            problems.push(format!("Group {} needs at least two words", g + 1));
+        }
+
+        for (w, q) in group.iter().enumerate() {
+
+            if q.0.trim().is_empty() {
+                problems.push(format!("Group {}: word {} is blank", g + 1, w + 1));
+            } else if group[..w].iter().any(|o| o.0.trim() == q.0.trim()) {
+                problems.push(format!("Group {}: \"{}\" appears twice", g + 1, q.0.trim()));
+            }
+
+            if q.1 == Choice::NONE && q.2 == Choice::NONE {
+                problems.push(format!("Group {}: \"{}\" has no MOST or LEAST key", g + 1, q.0.trim()));
+            }
+        }
+
+        for scale in [Choice::A, Choice::B, Choice::C, Choice::D, Choice::E, Choice::NONE] {
+
+            let name = if scale == Choice::NONE { "No key" } else { scale_name(scale) };
+
+            if group.iter().filter(|q| q.1 == scale).count() > 1 {
+                problems.push(format!("Group {}: {} is keyed more than once under MOST", g + 1, name));
+            }
+
+            if group.iter().filter(|q| q.2 == scale).count() > 1 {
+                problems.push(format!("Group {}: {} is keyed more than once under LEAST", g + 1, name));
+            }
+        }
+    }
+
+    return problems;
+}
+
+/// The scale a key scores on: D, i, S, C, E for the non-scoring words, or "-" for none.
+pub(crate) fn scale_name(choice: Choice) -> &'static str {
+
+    match choice {
+        Choice::A => "D",
+        Choice::B => "i",
+        Choice::C => "S",
+        Choice::D => "C",
+        Choice::E => "E",
+        Choice::NONE => "-"
+    }
+}
+
+fn parse_scale(name: &str) -> Option<Choice> {
+
+    match name.trim() {
+        "D" | "d" => Some(Choice::A),
+        "i" | "I" => Some(Choice::B),
+        "S" | "s" => Some(Choice::C),
+        "C" | "c" => Some(Choice::D),
+        "E" | "e" => Some(Choice::E),
+        "-" | "" => Some(Choice::NONE),
+        _ => None
+    }
+}
+
+/// How an answer sheet's groups and words were shuffled. Storing this is enough to show the same sheet again.
+#[derive(serde::Deserialize, serde::Serialize, PartialEq, Clone, Copy, Debug)]
+pub(crate) struct Shuffle {
+    pub(crate) seed: u64,
+    pub(crate) groups: bool,
+    pub(crate) words: bool
+}
+
+impl Shuffle {
+
+    /// A fresh random shuffle, or `None` when nothing is to be shuffled.
+    pub(crate) fn new(groups: bool, words: bool) -> Option<Self> {
+
+        if !groups && !words {
+            return None;
+        }
+
+        let mut seed = [0u8; 8];
+        getrandom::getrandom(&mut seed).ok()?;
+
+        return Some(Shuffle { seed: u64::from_le_bytes(seed), groups, words });
+    }
+}
+
+/// The order groups and words are shown in, as indices into the instrument's own order.
+pub(crate) struct Layout {
+    pub(crate) groups: Vec<usize>,
+    pub(crate) words: Vec<Vec<usize>>
+}
+
+impl Layout {
+
+    pub(crate) fn new(shuffle: Option<Shuffle>, questions: &Vec<Vec<(String, Choice, Choice)>>) -> Self {
+
+        let mut layout = Layout {
+            groups: (0..questions.len()).collect(),
+            words: questions.iter().map(|g| (0..g.len()).collect()).collect()
+        };
+
+        if let Some(shuffle) = shuffle {
+
+            let mut rng = SplitMix64(shuffle.seed);
+
+            if shuffle.groups { rng.shuffle(&mut layout.groups); }
+
+            if shuffle.words {
+                for words in layout.words.iter_mut() { rng.shuffle(words); }
+            }
+        }
+
+        return layout;
+    }
+
+    /// Where word `word` of group `group` appears on screen within its group.
+    pub(crate) fn word_position(&self, group: usize, word: usize) -> usize {
+
+        return self.words[group].iter().position(|w| *w == word).unwrap_or(word);
+    }
+}
+
+/// A tiny generator that gives the same sequence for a seed everywhere. Saved shuffles depend on it, so it must not change.
+struct SplitMix64(u64);
+
+impl SplitMix64 {
+
+    fn next(&mut self) -> u64 {
+
+        self.0 = self.0.wrapping_add(0x9e37_79b9_7f4a_7c15);
+
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+
+        return z ^ (z >> 31);
+    }
+
+    /// Fisher-Yates shuffle.
+    fn shuffle(&mut self, items: &mut Vec<usize>) {
+
+        for k in (1..items.len()).rev() {
+            let j = (self.next() % (k as u64 + 1)) as usize;
+            items.swap(k, j);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    /// A balanced two-group form.
+    fn form() -> Form {
+
+        let group = |words: [&str; 3]| vec![(words[0].to_owned(), Choice::A, Choice::A), (words[1].to_owned(), Choice::B, Choice::E), (words[2].to_owned(), Choice::E, Choice::C)];
+
+        return Form { name: "Form B".to_owned(), questions: vec![group(["bold", "lively", "humble"]), group(["driven", "warm", "quiet"])] };
+    }
+
+    fn rejects(form: &Form, problem: &str) {
+
+        let problems = validate(form);
+        assert!(problems.iter().any(|p| p == problem), "expected \"{}\" in {:?}", problem, problems);
+    }
+
+    #[test]
+    fn validate_accepts_balanced_forms() {
+
+        assert!(validate(&form()).is_empty());
+        assert!(validate(&Form { name: "Copy".to_owned(), ..standard_form() }).is_empty());
+    }
+
+    #[test]
+    fn validate_rejects_bad_names_and_empty_forms() {
+
+        rejects(&Form { name: " ".to_owned(), ..form() }, "The form needs a name");
+        rejects(&Form { name: STANDARD_FORM.to_owned(), ..form() }, "\"Standard\" is the name of the built-in form");
+        rejects(&Form { questions: vec![], ..form() }, "The form needs at least one group");
+    }
+
+    #[test]
+    fn validate_rejects_bad_words() {
+
+        let mut short = form();
+        short.questions[1].truncate(1);
+        rejects(&short, "Group 2 needs at least two words");
+
+        let mut blank = form();
+        blank.questions[0][1].0 = "  ".to_owned();
+        rejects(&blank, "Group 1: word 2 is blank");
+
+        let mut twice = form();
+        twice.questions[0][2].0 = " bold".to_owned();
+        rejects(&twice, "Group 1: \"bold\" appears twice");
+
+        let mut unkeyed = form();
+        unkeyed.questions[1][0].1 = Choice::NONE;
+        unkeyed.questions[1][0].2 = Choice::NONE;
+        rejects(&unkeyed, "Group 2: \"driven\" has no MOST or LEAST key");
+    }
+
+    #[test]
+    fn validate_rejects_repeated_keys_in_a_column() {
+
+        let mut most = form();
+        most.questions[0][1].1 = Choice::A;
+        rejects(&most, "Group 1: D is keyed more than once under MOST");
+
+        let mut least = form();
+        least.questions[1][2].2 = Choice::E;
+        rejects(&least, "Group 2: E is keyed more than once under LEAST");
+
+        let mut none = form();
+        none.questions[0][0].1 = Choice::NONE;
+        none.questions[0][1].1 = Choice::NONE;
+        rejects(&none, "Group 1: No key is keyed more than once under MOST");
+    }
+
+    #[test]
+    fn layout_without_shuffle_keeps_the_form_order() {
+
+        let questions = default_questions();
+        let layout = Layout::new(None, &questions);
+
+        assert_eq!(layout.groups, (0..questions.len()).collect::<Vec<usize>>());
+        assert_eq!(layout.word_position(3, 2), 2);
+    }
+
+    #[test]
+    fn layout_shuffles_the_same_way_for_a_seed() {
+
+        let questions = default_questions();
+        let shuffle = Shuffle { seed: 42, groups: true, words: true };
+
+        let layout = Layout::new(Some(shuffle), &questions);
+        let again = Layout::new(Some(shuffle), &questions);
+        assert_eq!((&layout.groups, &layout.words), (&again.groups, &again.words));
+
+        // Every group and word still appears exactly once, and positions find each word where it was put.
+        let mut groups = layout.groups.clone();
+        groups.sort();
+        assert_eq!(groups, (0..questions.len()).collect::<Vec<usize>>());
+
+        for (g, words) in layout.words.iter().enumerate() {
+
+            let mut sorted = words.clone();
+            sorted.sort();
+            assert_eq!(sorted, (0..questions[g].len()).collect::<Vec<usize>>());
+
+            for (position, w) in words.iter().enumerate() {
+                assert_eq!(layout.word_position(g, *w), position);
+            }
+        }
+
+        // Shuffling only words leaves the groups in order.
+        let words_only = Layout::new(Some(Shuffle { seed: 42, groups: false, words: true }), &questions);
+        assert_eq!(words_only.groups, (0..questions.len()).collect::<Vec<usize>>());
+    }
+}
@@ -1,4 +1,25 @@
 #![warn(clippy::all, rust_2018_idioms)]
 
 mod app;
+mod admin;
+mod answers;
+mod audit;
+mod batch;
+mod collab;
+#[cfg(not(target_arch = "wasm32"))]
+mod dataset;
+mod graph;
+mod highlights;
+mod instrument;
+mod library;
+mod paper;
+mod psychometrics;
+mod quality;
+mod reflection;
+mod report;
+mod stats;
+mod vault;
+pub mod cli;
+#[cfg(feature = "server")]
+pub mod server;
 pub use app::TemplateApp;
@@ -0,0 +1,144 @@
+use std::path::Path;
+
+use crate::app::{Profile, default_profiles};
+
+/// Edits kept for Undo on the Profile Editor page.
+const UNDO_LIMIT: usize = 100;
+
+/// The profile library file holds every classical pattern, in the order the pattern lookup uses:
+///
+/// ```json
+/// { "patterns": [{ "name": "Achiever", "aspects": [{ "label": "Emotions:", "value": ".." }, ..], "content": ".." }, ..] }
+/// ```
+#[derive(serde::Deserialize, serde::Serialize)]
+struct LibraryFile {
+    patterns: Vec<PatternEntry>
+}
+
+#[derive(serde::Deserialize, serde::Serialize)]
+struct PatternEntry {
+    name: String,
+    aspects: Vec<AspectEntry>,
+    #[serde(default)]
+    content: String
+}
+
+#[derive(serde::Deserialize, serde::Serialize)]
+struct AspectEntry {
+    label: String,
+    value: String
+}
+
+pub(crate) fn load_library(path: &Path) -> Result<Vec<Profile>, String> {
+
+    let text = std::fs::read_to_string(path).map_err(|e| format!("{}: {}", path.display(), e))?;
+    let file: LibraryFile = serde_json::from_str(&text).map_err(|e| format!("{}: {}", path.display(), e))?;
+
+    let expected = default_profiles().len();
+
+    if file.patterns.len() != expected {
+        return Err(format!("{}: expected {} patterns but found {}", path.display(), expected, file.patterns.len()));
+    }
+
+    return Ok(file.patterns.into_iter().map(|p| Profile {
+        name: p.name,
+        aspects: p.aspects.into_iter().map(|a| (a.label, a.value)).collect(),
+        content: p.content
+    }).collect());
+}
+
+pub(crate) fn save_library(profiles: &Vec<Profile>, path: &Path) -> Result<(), String> {
+
+    let file = LibraryFile {
+        patterns: profiles.iter().map(|p| PatternEntry {
+            name: p.name.to_owned(),
+            aspects: p.aspects.iter().map(|a| AspectEntry { label: a.0.to_owned(), value: a.1.to_owned() }).collect(),
+            content: p.content.to_owned()
+        }).collect()
+    };
+
+    let text = serde_json::to_string_pretty(&file).map_err(|e| e.to_string())?;
+
+    return std::fs::write(path, text).map_err(|e| format!("{}: {}", path.display(), e));
+}
+
+/// State of the Profile Editor page.
+#[derive(serde::Deserialize, serde::Serialize)]
+#[serde(default)]
+pub(crate) struct ProfileEditor {
+    pub(crate) path: String,
+
+    #[serde(skip)]
+    pub(crate) status: String,
+
+    /// Index of the pattern being edited.
+    #[serde(skip)]
+    pub(crate) selected: usize,
+
+    #[serde(skip)]
+    undo: Vec<Vec<Profile>>,
+
+    /// (pattern, field) typed in last, so a run of keystrokes in one field undoes as one edit.
+    #[serde(skip)]
+    last_edit: Option<(usize, usize)>
+}
+
+impl Default for ProfileEditor {
+    fn default() -> Self {
+        Self {
+            path: "profiles.json".to_owned(),
+            status: "".to_owned(),
+            selected: 0,
+            undo: vec![],
+            last_edit: None
+        }
+    }
+}
+
+impl ProfileEditor {
+
+    /// Remembers the patterns as they were before an edit. `field` is `None` for edits that
+    /// should always be undone on their own, such as adding or removing an aspect.
+    pub(crate) fn record(&mut self, before: Vec<Profile>, field: Option<(usize, usize)>) {
+
+        if field.is_none() || field != self.last_edit {
+
+            self.undo.push(before);
+
+            if self.undo.len() > UNDO_LIMIT { self.undo.remove(0); }
+        }
+
+        self.last_edit = field;
+    }
+
+    pub(crate) fn can_undo(&self) -> bool {
+
+        return !self.undo.is_empty();
+    }
+
+    pub(crate) fn undo(&mut self, profiles: &mut Vec<Profile>) {
+
+        if let Some(before) = self.undo.pop() {
+            *profiles = before;
+        }
+
+        self.last_edit = None;
+    }
+}
+
+/// Which field of a pattern differs: 0 for the name, 1 for the content, 2 + 2k and 3 + 2k for
+/// aspect k's label and value. `None` when the aspects were added, removed or reordered.
+pub(crate) fn edited_field(before: &Profile, after: &Profile) -> Option<usize> {
+
+    if before.name != after.name { return Some(0); }
+    if before.content != after.content { return Some(1); }
+    if before.aspects.len() != after.aspects.len() { return None; }
+
+    let changed: Vec<usize> = (0..after.aspects.len()).filter(|k| before.aspects[*k] != after.aspects[*k]).collect();
+
+    return match changed[..] {
+        [k] if before.aspects[k].0 != after.aspects[k].0 && before.aspects[k].1 == after.aspects[k].1 => Some(2 + 2 * k),
+        [k] if before.aspects[k].0 == after.aspects[k].0 => Some(3 + 2 * k),
+        _ => None
+    };
+}
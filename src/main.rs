@@ -7,11 +7,22 @@ fn main() -> eframe::Result<()> {
     // Log to stdout (if you run with `RUST_LOG=debug`).
     tracing_subscriber::fmt::init();
 
-    let native_options = eframe::NativeOptions::default();
+    // Subcommands such as `disc graph` run without opening a window.
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    if let Some(code) = disc::cli::run(&args) {
+        std::process::exit(code);
+    }
+
+    let kiosk = args.iter().any(|a| a == "--kiosk");
+
+    let native_options = eframe::NativeOptions {
+        fullscreen: kiosk,
+        ..Default::default()
+    };
     eframe::run_native(
         "DiSC Program - Ported By Braeden Moore",
         native_options,
-        Box::new(|cc| Box::new(disc::TemplateApp::new(cc))),
+        Box::new(move |cc| Box::new(if kiosk { disc::TemplateApp::new_kiosk(cc) } else { disc::TemplateApp::new(cc) })),
     )
 }
 
@@ -0,0 +1,106 @@
+//! Printable pages for sites that run the assessment on paper.
+//!
+//! Both pages are built from the loaded instrument, so the paper form always shows the same words, in the
+//! same order, as the Response page and the answer files that `disc batch` reads. Print them from a browser,
+//! or print to PDF there for a file to send out.
+
+use std::path::Path;
+
+use crate::app::Choice;
+use crate::graph::escape_xml;
+use crate::instrument::scale_name;
+
+const STYLE: &str = "
+body { font-family: sans-serif; color: #333; max-width: 60em; margin: 2em auto; padding: 0 1em; line-height: 1.4; }
+h1 { margin-bottom: 0; }
+.details { display: grid; grid-template-columns: max-content 1fr max-content 1fr; gap: 0.8em 1em; margin: 1.5em 0; }
+.details span { border-bottom: 1px solid #333; }
+.groups { display: grid; grid-template-columns: repeat(2, 1fr); gap: 1em 2em; }
+table { border-collapse: collapse; width: 100%; break-inside: avoid; }
+th, td { border: 1px solid #999; padding: 0.3em 0.5em; }
+th { background: #f4f9fc; font-size: 0.8em; }
+td.mark { width: 4em; text-align: center; }
+caption { text-align: left; font-weight: bold; color: #3a8fb7; }
+.totals td { height: 1.6em; }
+@media print { body { margin: 0; max-width: none; } }
+";
+
+/// The answer sheet: one table per group with empty MOST and LEAST boxes to tick.
+pub(crate) fn answer_sheet_html(form: &str, questions: &Vec<Vec<(String, Choice, Choice)>>) -> String {
+
+    let mut html = page_head(&format!("DiSC Answer Sheet - {}", form));
+
+    html += "<div class=\"details\"><b>Name</b><span></span><b>Date</b><span></span><b>Focus</b><span></span></div>\n";
+    html += &format!("<p>Each of the {} groups below lists words that might describe you. In every group, tick the one word that is <b>MOST</b> like you \
+                      and the one word that is <b>LEAST</b> like you. Tick exactly one of each, and never the same word twice.</p>\n", questions.len());
+
+    html += &groups_html(questions, |_| ("".to_owned(), "".to_owned()));
+
+    html += "</body>\n</html>\n";
+    return html;
+}
+
+/// The facilitator's key: the scale each word scores as MOST and as LEAST, and a worksheet for the totals.
+pub(crate) fn scoring_key_html(form: &str, questions: &Vec<Vec<(String, Choice, Choice)>>) -> String {
+
+    let mut html = page_head(&format!("DiSC Scoring Key - {}", form));
+
+    html += "<p>For each group, look up the scale of the word ticked MOST and of the word ticked LEAST. A dash means the word does not score in that column. \
+             The tally for a scale is its MOST count minus its LEAST count. To score with the program instead, type each sheet into an answer file, \
+             one line per group in this order, as \"MOST word, LEAST word\".</p>\n";
+
+    html += &groups_html(questions, |q| (key_mark(q.1).to_owned(), key_mark(q.2).to_owned()));
+
+    html += "<h2>Totals</h2>\n<table class=\"totals\">\n<tr><th></th><th>D</th><th>i</th><th>S</th><th>C</th></tr>\n";
+
+    for row in ["MOST", "LEAST", "Tally (MOST - LEAST)"] {
+        html += &format!("<tr><th>{}</th><td></td><td></td><td></td><td></td></tr>\n", row);
+    }
+
+    html += "</table>\n</body>\n</html>\n";
+    return html;
+}
+
+/// Writes the answer sheet to `sheet` and the scoring key to `key`.
+pub(crate) fn write_paper_forms(form: &str, questions: &Vec<Vec<(String, Choice, Choice)>>, sheet: &Path, key: &Path) -> Result<(), String> {
+
+    std::fs::write(sheet, answer_sheet_html(form, questions)).map_err(|e| format!("{}: {}", sheet.display(), e))?;
+
+    return std::fs::write(key, scoring_key_html(form, questions)).map_err(|e| format!("{}: {}", key.display(), e));
+}
+
+/// The scale a word scores as on the key, or a dash when it doesn't score in that column.
+fn key_mark(choice: Choice) -> &'static str {
+
+    match choice {
+        Choice::E | Choice::NONE => "-",
+        _ => scale_name(choice)
+    }
+}
+
+fn page_head(title: &str) -> String {
+
+    return format!("<!DOCTYPE html>\n<html lang=\"en\">\n<head>\n<meta charset=\"utf-8\">\n<title>{title}</title>\n<style>{style}</style>\n</head>\n<body>\n<h1>{title}</h1>\n",
+                   title = escape_xml(title), style = STYLE);
+}
+
+/// Every group as a numbered table of words, with `marks` filling the MOST and LEAST cells.
+fn groups_html(questions: &Vec<Vec<(String, Choice, Choice)>>, marks: impl Fn(&(String, Choice, Choice)) -> (String, String)) -> String {
+
+    let mut html = "<div class=\"groups\">\n".to_owned();
+
+    for (g, group) in questions.iter().enumerate() {
+
+        html += &format!("<table>\n<caption>Group {}</caption>\n<tr><th>Word</th><th>MOST</th><th>LEAST</th></tr>\n", g + 1);
+
+        for q in group {
+            let (most, least) = marks(q);
+            html += &format!("<tr><td>{}</td><td class=\"mark\">{}</td><td class=\"mark\">{}</td></tr>\n", escape_xml(&q.0), most, least);
+        }
+
+        html += "</table>\n";
+    }
+
+    html += "</div>\n";
+    return html;
+}
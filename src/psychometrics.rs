@@ -0,0 +1,257 @@
+use crate::app::Choice;
+use crate::audit::{Column, ResponseEvent};
+
+/// Respondents needed before any of the analysis means anything.
+pub(crate) const MIN_RESPONDENTS: usize = 3;
+
+/// Item-total correlations below this mark a word as weak.
+pub(crate) const WEAK_CORRELATION: f64 = 0.2;
+
+/// One respondent's answer sheet, as the analysis needs it.
+pub(crate) struct ItemRecord<'a> {
+    pub(crate) responses: &'a Vec<(Choice, Choice)>,
+    pub(crate) events: &'a Vec<ResponseEvent>
+}
+
+/// How one word performed across every respondent.
+pub(crate) struct ItemStats {
+    pub(crate) group: usize,
+    pub(crate) word: String,
+    /// Share of respondents choosing it as MOST and as LEAST.
+    pub(crate) most_rate: f64,
+    pub(crate) least_rate: f64,
+    /// Correlation of choosing the word with the rest of its MOST scale (or LEAST scale for words
+    /// that only score there), counting only the picks that score on that scale; `None` for
+    /// non-scoring words or when nothing varies.
+    pub(crate) item_total: Option<f64>
+}
+
+/// Reliability of the four scales and statistics for each word.
+///
+/// Items are scored +1 when chosen as MOST, -1 when chosen as LEAST and 0 otherwise, counting each side
+/// only where it is keyed to the scale, so a scale's items add up to its tally. Forced-choice (ipsative) answers make the scales depend on each other,
+/// which pulls alpha and the correlations down; compare forms with each other rather than against
+/// published norms for normative tests.
+pub(crate) struct Psychometrics {
+    pub(crate) respondents: usize,
+    /// Cronbach's alpha for D, i, S and C, when the scale has at least two items that vary.
+    pub(crate) alpha: [Option<f64>; 4],
+    pub(crate) items: Vec<ItemStats>
+}
+
+pub(crate) fn analyze(questions: &Vec<Vec<(String, Choice, Choice)>>, records: &[ItemRecord<'_>]) -> Option<Psychometrics> {
+
+    if records.len() < MIN_RESPONDENTS {
+        return None;
+    }
+
+    let chosen: Vec<Vec<(Option<usize>, Option<usize>)>> = records.iter().map(|r| chosen_words(questions, r)).collect();
+    let scales = [Choice::A, Choice::B, Choice::C, Choice::D];
+
+    // scores[s][g][r]: respondent r's score on scale s from group g.
+    let scores: Vec<Vec<Vec<f64>>> = scales.iter().map(|scale| {
+        (0..questions.len()).map(|g| records.iter().map(|r| {
+            let response = r.responses[g];
+            (response.0 == *scale) as i8 as f64 - (response.1 == *scale) as i8 as f64
+        }).collect()).collect()
+    }).collect();
+
+    let totals: Vec<Vec<f64>> = scores.iter().map(|groups| (0..records.len()).map(|r| groups.iter().map(|g| g[r]).sum()).collect()).collect();
+
+    let alpha = [0, 1, 2, 3].map(|s| cronbach_alpha(&scores[s], &totals[s]));
+
+    let mut items = vec![];
+
+    for (g, group) in questions.iter().enumerate() {
+
+        for (w, q) in group.iter().enumerate() {
+
+            let most = chosen.iter().filter(|c| c[g].0 == Some(w)).count();
+            let least = chosen.iter().filter(|c| c[g].1 == Some(w)).count();
+
+            let scale = scales.iter().position(|s| *s == q.1).or(scales.iter().position(|s| *s == q.2));
+
+            let item_total = scale.and_then(|s| {
+
+                // A word keyed E on one side, such as "humble", only scores when picked on the other.
+                let item: Vec<f64> = chosen.iter().map(|c| (c[g].0 == Some(w) && q.1 == scales[s]) as i8 as f64 - (c[g].1 == Some(w) && q.2 == scales[s]) as i8 as f64).collect();
+                let rest: Vec<f64> = (0..records.len()).map(|r| totals[s][r] - scores[s][g][r]).collect();
+
+                correlation(&item, &rest)
+            });
+
+            items.push(ItemStats {
+                group: g,
+                word: q.0.to_owned(),
+                most_rate: most as f64 / records.len() as f64,
+                least_rate: least as f64 / records.len() as f64,
+                item_total
+            });
+        }
+    }
+
+    return Some(Psychometrics { respondents: records.len(), alpha, items });
+}
+
+/// The word chosen as MOST and as LEAST in each group, preferring the recorded clicks over the scale keys.
+pub(crate) fn chosen_words(questions: &Vec<Vec<(String, Choice, Choice)>>, record: &ItemRecord<'_>) -> Vec<(Option<usize>, Option<usize>)> {
+
+    let mut chosen: Vec<(Option<usize>, Option<usize>)> = vec![(None, None); questions.len()];
+
+    for (g, (group, response)) in questions.iter().zip(record.responses).enumerate() {
+
+        // Only keys that identify a single word say which word it was.
+        if response.0 != Choice::NONE && group.iter().filter(|q| q.1 == response.0).count() == 1 {
+            chosen[g].0 = group.iter().position(|q| q.1 == response.0);
+        }
+
+        if response.1 != Choice::NONE && group.iter().filter(|q| q.2 == response.1).count() == 1 {
+            chosen[g].1 = group.iter().position(|q| q.2 == response.1);
+        }
+    }
+
+    // The last click in each column wins.
+    for event in record.events.iter().filter(|e| e.group < questions.len()) {
+
+        let w = questions[event.group].iter().position(|q| q.0 == event.word);
+
+        match event.column {
+            Column::Most if w.is_some() => chosen[event.group].0 = w,
+            Column::Least if w.is_some() => chosen[event.group].1 = w,
+            _ => ()
+        }
+    }
+
+    return chosen;
+}
+
+/// Cronbach's alpha from each item's scores and the totals, ignoring items that never vary.
+fn cronbach_alpha(items: &Vec<Vec<f64>>, totals: &[f64]) -> Option<f64> {
+
+    let varying: Vec<f64> = items.iter().map(|i| variance(i)).filter(|v| *v > 0.0).collect();
+    let k = varying.len() as f64;
+    let total = variance(totals);
+
+    if varying.len() < 2 || total == 0.0 {
+        return None;
+    }
+
+    return Some(k / (k - 1.0) * (1.0 - varying.iter().sum::<f64>() / total));
+}
+
+fn correlation(x: &[f64], y: &[f64]) -> Option<f64> {
+
+    let n = x.len() as f64;
+    let (mx, my) = (x.iter().sum::<f64>() / n, y.iter().sum::<f64>() / n);
+
+    let cov: f64 = x.iter().zip(y).map(|(a, b)| (a - mx) * (b - my)).sum();
+    let (sx, sy) = (x.iter().map(|a| (a - mx).powi(2)).sum::<f64>().sqrt(), y.iter().map(|b| (b - my).powi(2)).sum::<f64>().sqrt());
+
+    if sx == 0.0 || sy == 0.0 {
+        return None;
+    }
+
+    return Some(cov / (sx * sy));
+}
+
+fn variance(values: &[f64]) -> f64 {
+
+    let n = values.len() as f64;
+
+    if values.len() < 2 {
+        return 0.0;
+    }
+
+    let mean = values.iter().sum::<f64>() / n;
+    return values.iter().map(|v| (v - mean).powi(2)).sum::<f64>() / (n - 1.0);
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    #[test]
+    fn alpha_matches_a_hand_worked_example() {
+
+        // Item variances 1 and 1; totals 2, 5, 5 have variance 3, so alpha = 2 * (1 - 2 / 3).
+        let items = vec![vec![1.0, 2.0, 3.0], vec![1.0, 3.0, 2.0]];
+        let totals = [2.0, 5.0, 5.0];
+
+        assert!((cronbach_alpha(&items, &totals).unwrap() - 2.0 / 3.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn alpha_is_one_for_identical_items_and_needs_two_that_vary() {
+
+        let same = vec![vec![1.0, 1.0, 0.0, 0.0], vec![1.0, 1.0, 0.0, 0.0]];
+        assert!((cronbach_alpha(&same, &[2.0, 2.0, 0.0, 0.0]).unwrap() - 1.0).abs() < 1e-12);
+
+        let one_varies = vec![vec![1.0, 0.0, 1.0], vec![1.0, 1.0, 1.0]];
+        assert!(cronbach_alpha(&one_varies, &[2.0, 1.0, 2.0]).is_none());
+    }
+
+    #[test]
+    fn correlation_handles_perfect_and_constant_series() {
+
+        assert!((correlation(&[1.0, 2.0, 3.0], &[2.0, 4.0, 6.0]).unwrap() - 1.0).abs() < 1e-12);
+        assert!((correlation(&[1.0, 2.0, 3.0], &[3.0, 2.0, 1.0]).unwrap() + 1.0).abs() < 1e-12);
+        assert!((correlation(&[1.0, 2.0, 3.0], &[1.0, 3.0, 2.0]).unwrap() - 0.5).abs() < 1e-12);
+        assert!(correlation(&[1.0, 2.0, 3.0], &[4.0, 4.0, 4.0]).is_none());
+    }
+
+    #[test]
+    fn item_total_correlation_and_rates_from_answer_sheets() {
+
+        let questions = vec![
+            vec![("a".to_owned(), Choice::A, Choice::A), ("b".to_owned(), Choice::B, Choice::B)],
+            vec![("c".to_owned(), Choice::A, Choice::A), ("d".to_owned(), Choice::B, Choice::B)]
+        ];
+
+        // Everyone answers both groups the same way, so each D word tracks the rest of the D scale exactly.
+        let sheets = vec![
+            vec![(Choice::A, Choice::B), (Choice::A, Choice::B)],
+            vec![(Choice::B, Choice::A), (Choice::B, Choice::A)],
+            vec![(Choice::A, Choice::B), (Choice::A, Choice::B)]
+        ];
+        let events = vec![];
+        let records: Vec<ItemRecord<'_>> = sheets.iter().map(|responses| ItemRecord { responses, events: &events }).collect();
+
+        let result = analyze(&questions, &records).unwrap();
+        let a = result.items.iter().find(|i| i.word == "a").unwrap();
+
+        assert_eq!(result.respondents, 3);
+        assert!((a.most_rate - 2.0 / 3.0).abs() < 1e-12);
+        assert!((a.least_rate - 1.0 / 3.0).abs() < 1e-12);
+        assert!((a.item_total.unwrap() - 1.0).abs() < 1e-12);
+
+        assert!(analyze(&questions, &records[..2]).is_none());
+    }
+
+    #[test]
+    fn item_total_ignores_picks_on_a_non_scoring_side() {
+
+        // "h" scores D only as LEAST; picking it as MOST scores nothing.
+        let questions = vec![
+            vec![("a".to_owned(), Choice::A, Choice::E), ("b".to_owned(), Choice::B, Choice::B), ("h".to_owned(), Choice::E, Choice::A)],
+            vec![("c".to_owned(), Choice::A, Choice::A), ("d".to_owned(), Choice::B, Choice::B)]
+        ];
+
+        let sheets = vec![
+            vec![(Choice::E, Choice::B), (Choice::A, Choice::B)],
+            vec![(Choice::A, Choice::A), (Choice::B, Choice::A)],
+            vec![(Choice::B, Choice::A), (Choice::B, Choice::A)],
+            vec![(Choice::A, Choice::B), (Choice::B, Choice::A)]
+        ];
+        let events = vec![];
+        let records: Vec<ItemRecord<'_>> = sheets.iter().map(|responses| ItemRecord { responses, events: &events }).collect();
+
+        let result = analyze(&questions, &records).unwrap();
+        let h = result.items.iter().find(|i| i.word == "h").unwrap();
+
+        // Item 0, -1, -1, 0 against the rest of D, 1, -1, -1, -1: covariance sum 1, squared deviations 1 and 3,
+        // so r = 1 / sqrt(3). Counting the MOST pick as +1 would give 0.87 instead.
+        assert!((h.most_rate - 0.25).abs() < 1e-12);
+        assert!((h.item_total.unwrap() - 1.0 / 3.0f64.sqrt()).abs() < 1e-12);
+    }
+}
@@ -0,0 +1,303 @@
+use chrono::{DateTime, Utc};
+
+use crate::app::Choice;
+use crate::audit::{Column, ResponseEvent, summarize};
+use crate::instrument::{Layout, Shuffle};
+
+/// Completion time assumed typical until enough sessions have been saved to measure it, in seconds.
+const DEFAULT_NORM_SECONDS: f64 = 300.0;
+
+/// Saved sessions with timings needed before their median replaces `DEFAULT_NORM_SECONDS`.
+const NORM_MIN_SESSIONS: usize = 5;
+
+/// Finishing in less than this fraction of the norm counts as speeding.
+const SPEEDING_FRACTION: f64 = 0.4;
+
+/// Share of groups answered in the same position that counts as straight-lining.
+const POSITION_SHARE: f64 = 0.85;
+
+/// Standard deviations above the random-answering count of non-scoring picks before they are flagged,
+/// about the top 2% of people answering at random.
+const NON_SCORING_SDS: f64 = 2.0;
+
+/// A tally whose highest and lowest dimensions are at most this far apart is flat.
+const FLAT_SPREAD: i8 = 4;
+
+/// A reason to treat a result with caution, shown as a warning badge.
+pub(crate) struct QualityFlag {
+    pub(crate) title: String,
+    pub(crate) detail: String
+}
+
+/// Everything known about one answer sheet that the checks look at.
+pub(crate) struct SheetRecord<'a> {
+    pub(crate) responses: &'a Vec<(Choice, Choice)>,
+    pub(crate) tally: (i8, i8, i8, i8),
+    pub(crate) started: Option<DateTime<Utc>>,
+    pub(crate) events: &'a Vec<ResponseEvent>,
+    pub(crate) shuffle: Option<Shuffle>
+}
+
+/// Runs every validity check. `norm_times` are the completion times of earlier sessions, in seconds.
+pub(crate) fn quality_flags(questions: &Vec<Vec<(String, Choice, Choice)>>, sheet: &SheetRecord<'_>, norm_times: &[f64]) -> Vec<QualityFlag> {
+
+    let mut flags = vec![];
+
+    flags.extend(speeding(sheet, questions.len(), norm_times));
+    flags.extend(positional(questions, sheet));
+    flags.extend(non_scoring(questions, sheet));
+    flags.extend(flat_tally(sheet));
+
+    return flags;
+}
+
+/// Total seconds a sheet took, if it was timed.
+pub(crate) fn completion_time(started: Option<DateTime<Utc>>, events: &[ResponseEvent], groups: usize) -> Option<f64> {
+
+    if events.is_empty() {
+        return None;
+    }
+
+    return Some(summarize(started, events, groups).total);
+}
+
+fn speeding(sheet: &SheetRecord<'_>, groups: usize, norm_times: &[f64]) -> Option<QualityFlag> {
+
+    let total = completion_time(sheet.started, sheet.events, groups)?;
+
+    let norm = if norm_times.len() >= NORM_MIN_SESSIONS { median(norm_times) } else { DEFAULT_NORM_SECONDS };
+
+    if total >= norm * SPEEDING_FRACTION {
+        return None;
+    }
+
+    return Some(QualityFlag {
+        title: "Speeding".to_owned(),
+        detail: format!("Finished in {:.0} seconds, against a norm of {:.0} seconds.", total, norm)
+    });
+}
+
+/// The same position chosen as MOST (or LEAST) in nearly every group, such as always the first word.
+fn positional(questions: &Vec<Vec<(String, Choice, Choice)>>, sheet: &SheetRecord<'_>) -> Vec<QualityFlag> {
+
+    let mut flags = vec![];
+
+    for column in [Column::Most, Column::Least] {
+
+        let positions = chosen_positions(questions, sheet, column);
+        let answered = positions.iter().filter(|p| p.is_some()).count();
+
+        if answered == 0 {
+            continue;
+        }
+
+        let width = questions.iter().map(|g| g.len()).max().unwrap_or(0);
+
+        for position in 0..width {
+
+            let count = positions.iter().filter(|p| **p == Some(position)).count();
+
+            if count as f64 >= POSITION_SHARE * questions.len() as f64 {
+
+                flags.push(QualityFlag {
+                    title: "Straight-lining".to_owned(),
+                    detail: format!("Word {} was chosen as {} in {} of {} groups.", position + 1, match column { Column::Most => "MOST", Column::Least => "LEAST" }, count, questions.len())
+                });
+            }
+        }
+    }
+
+    return flags;
+}
+
+/// Where on screen the word chosen in each group's column was, preferring the recorded clicks over the scale keys.
+fn chosen_positions(questions: &Vec<Vec<(String, Choice, Choice)>>, sheet: &SheetRecord<'_>, column: Column) -> Vec<Option<usize>> {
+
+    let layout = Layout::new(sheet.shuffle, questions);
+    let mut positions: Vec<Option<usize>> = vec![None; questions.len()];
+
+    if !sheet.events.is_empty() {
+
+        for event in sheet.events.iter().filter(|e| e.column == column && e.group < questions.len()) {
+            positions[event.group] = questions[event.group].iter().position(|q| q.0 == event.word).map(|w| layout.word_position(event.group, w));
+        }
+
+        return positions;
+    }
+
+    for (g, (group, response)) in questions.iter().zip(sheet.responses).enumerate() {
+
+        let (choice, key): (Choice, fn(&(String, Choice, Choice)) -> Choice) = match column {
+            Column::Most => (response.0, |q| q.1),
+            Column::Least => (response.1, |q| q.2)
+        };
+
+        // Only count words whose key identifies them.
+        if choice != Choice::NONE && group.iter().filter(|q| key(q) == choice).count() == 1 {
+            positions[g] = group.iter().position(|q| key(q) == choice).map(|w| layout.word_position(g, w));
+        }
+    }
+
+    return positions;
+}
+
+/// Non-scoring answers chosen clearly more often than answering at random would.
+///
+/// Each column of each group is a chance of picking a non-scoring word, so the random count is binomial-like
+/// with mean sum(p) and variance sum(p(1 - p)); only counts `NON_SCORING_SDS` deviations above the mean are heavy use.
+fn non_scoring(questions: &Vec<Vec<(String, Choice, Choice)>>, sheet: &SheetRecord<'_>) -> Option<QualityFlag> {
+
+    let count = sheet.responses.iter().map(|r| (r.0 == Choice::E) as usize + (r.1 == Choice::E) as usize).sum::<usize>();
+
+    let chances: Vec<f64> = questions.iter()
+        .flat_map(|g| {
+            let n = g.len().max(1) as f64;
+            [g.iter().filter(|q| q.1 == Choice::E).count() as f64 / n, g.iter().filter(|q| q.2 == Choice::E).count() as f64 / n]
+        })
+        .collect();
+
+    let expected: f64 = chances.iter().sum();
+    let sd = chances.iter().map(|p| p * (1.0 - p)).sum::<f64>().sqrt();
+
+    if count == 0 || count as f64 <= expected + NON_SCORING_SDS * sd {
+        return None;
+    }
+
+    return Some(QualityFlag {
+        title: "Non-scoring answers".to_owned(),
+        detail: format!("{} answers were non-scoring words; random answering would give about {:.0}.", count, expected)
+    });
+}
+
+fn flat_tally(sheet: &SheetRecord<'_>) -> Option<QualityFlag> {
+
+    let tally = [sheet.tally.0, sheet.tally.1, sheet.tally.2, sheet.tally.3];
+
+    // A blank sheet is unanswered, not flat.
+    if tally == [0; 4] {
+        return None;
+    }
+
+    let spread = tally.iter().max()? - tally.iter().min()?;
+
+    if spread > FLAT_SPREAD {
+        return None;
+    }
+
+    return Some(QualityFlag {
+        title: "Flat profile".to_owned(),
+        detail: format!("The four tallies are within {} of each other, so no style stands out.", spread)
+    });
+}
+
+fn median(values: &[f64]) -> f64 {
+
+    let mut sorted = values.to_vec();
+    sorted.sort_by(|a, b| a.partial_cmp(b).unwrap_or(std::cmp::Ordering::Equal));
+
+    let n = sorted.len();
+    return if n % 2 == 0 { (sorted[n / 2 - 1] + sorted[n / 2]) / 2.0 } else { sorted[n / 2] };
+}
+
+#[cfg(test)]
+mod tests {
+
+    use chrono::TimeZone;
+
+    use super::*;
+
+    /// `groups` groups of four words, keyed D, i, S, C on both sides except that `e` words per group are non-scoring.
+    fn form(groups: usize, e: usize) -> Vec<Vec<(String, Choice, Choice)>> {
+
+        let keys = [Choice::A, Choice::B, Choice::C, Choice::D];
+
+        return (0..groups).map(|g| (0..4).map(|w| {
+            let key = if w >= 4 - e { Choice::E } else { keys[w] };
+            (format!("word{}.{}", g, w), key, key)
+        }).collect()).collect();
+    }
+
+    fn at(second: u32) -> DateTime<Utc> {
+
+        return Utc.with_ymd_and_hms(2024, 1, 1, 9, 0, 0).unwrap() + chrono::Duration::seconds(second as i64);
+    }
+
+    #[test]
+    fn speeding_compares_against_the_default_norm_until_enough_sessions() {
+
+        let questions = form(2, 0);
+        let responses = vec![(Choice::A, Choice::B); 2];
+        let events = vec![ResponseEvent { group: 0, word: "word0.0".to_owned(), column: Column::Most, at: at(60) }];
+        let sheet = SheetRecord { responses: &responses, tally: (2, -2, 0, 0), started: Some(at(0)), events: &events, shuffle: None };
+
+        // 60 seconds is under 40% of the default 300.
+        assert!(speeding(&sheet, questions.len(), &[]).is_some());
+        assert!(speeding(&sheet, questions.len(), &[100.0; 4]).is_some());
+
+        // Five earlier sessions of 100 seconds set the norm, and 60 is over 40% of it.
+        assert!(speeding(&sheet, questions.len(), &[100.0; 5]).is_none());
+
+        let untimed = SheetRecord { events: &vec![], ..sheet };
+        assert!(speeding(&untimed, questions.len(), &[]).is_none());
+    }
+
+    #[test]
+    fn positional_flags_the_same_position_in_nearly_every_group() {
+
+        let questions = form(10, 0);
+        let least = [Choice::B, Choice::C, Choice::D];
+
+        // Always the first word as MOST; LEAST spread over the other three.
+        let responses: Vec<(Choice, Choice)> = (0..10).map(|g| (Choice::A, least[g % 3])).collect();
+        let sheet = SheetRecord { responses: &responses, tally: (10, -4, -3, -3), started: None, events: &vec![], shuffle: None };
+
+        let flags = positional(&questions, &sheet);
+
+        assert_eq!(flags.len(), 1);
+        assert_eq!(flags[0].detail, "Word 1 was chosen as MOST in 10 of 10 groups.");
+    }
+
+    #[test]
+    fn positional_prefers_recorded_clicks_over_keys() {
+
+        let questions = form(10, 0);
+        let responses = vec![(Choice::A, Choice::B); 10];
+
+        // The keys say word 1 every time, but the clicks say the MOST picks moved around and LEAST was never clicked.
+        let events: Vec<ResponseEvent> = (0..10).map(|g| ResponseEvent { group: g, word: format!("word{}.{}", g, g % 4), column: Column::Most, at: at(g as u32) }).collect();
+        let sheet = SheetRecord { responses: &responses, tally: (10, -10, 0, 0), started: None, events: &events, shuffle: None };
+
+        assert!(positional(&questions, &sheet).is_empty());
+    }
+
+    #[test]
+    fn non_scoring_flags_only_clearly_more_than_random() {
+
+        // One non-scoring word in four, over 20 columns: random answering gives 5, with a standard deviation of
+        // sqrt(20 * 0.25 * 0.75) = 1.94, so the cutoff is 5 + 2 * 1.94 = 8.87.
+        let questions = form(10, 1);
+        let answers = |count: usize| -> Vec<(Choice, Choice)> {
+            return (0..10).map(|g| (if 2 * g < count { Choice::E } else { Choice::A }, if 2 * g + 1 < count { Choice::E } else { Choice::B })).collect();
+        };
+
+        let eight = answers(8);
+        let nine = answers(9);
+
+        assert!(non_scoring(&questions, &SheetRecord { responses: &eight, tally: (0, 0, 0, 0), started: None, events: &vec![], shuffle: None }).is_none());
+        assert!(non_scoring(&questions, &SheetRecord { responses: &nine, tally: (0, 0, 0, 0), started: None, events: &vec![], shuffle: None }).is_some());
+
+        // A form without non-scoring words never flags.
+        assert!(non_scoring(&form(10, 0), &SheetRecord { responses: &answers(0), tally: (0, 0, 0, 0), started: None, events: &vec![], shuffle: None }).is_none());
+    }
+
+    #[test]
+    fn flat_tally_flags_close_tallies_but_not_blank_sheets() {
+
+        let (responses, events) = (vec![], vec![]);
+        let sheet = |tally| SheetRecord { responses: &responses, tally, started: None, events: &events, shuffle: None };
+
+        assert!(flat_tally(&sheet((2, 1, 0, -2))).is_some());
+        assert!(flat_tally(&sheet((3, 1, 0, -2))).is_none());
+        assert!(flat_tally(&sheet((0, 0, 0, 0))).is_none());
+    }
+}
@@ -0,0 +1,38 @@
+use chrono::NaiveDate;
+
+/// What a participant wrote after reading their results.
+#[derive(serde::Deserialize, serde::Serialize, PartialEq, Clone, Debug, Default)]
+pub(crate) struct Reflection {
+    pub(crate) notes: String,
+
+    /// Labels of the Profile Pattern aspects that ring true, such as "Goal:".
+    pub(crate) resonant: Vec<String>,
+
+    pub(crate) actions: Vec<ActionItem>
+}
+
+/// One goal in the action plan.
+#[derive(serde::Deserialize, serde::Serialize, PartialEq, Clone, Debug)]
+pub(crate) struct ActionItem {
+    pub(crate) text: String,
+    pub(crate) due: Option<NaiveDate>,
+    pub(crate) done: bool
+}
+
+impl Reflection {
+
+    pub(crate) fn is_empty(&self) -> bool {
+
+        return self.notes.trim().is_empty() && self.resonant.is_empty() && self.actions.is_empty();
+    }
+}
+
+/// Reads a due date typed as `YYYY-MM-DD`; a blank field means no date.
+pub(crate) fn parse_due(text: &str) -> Result<Option<NaiveDate>, String> {
+
+    if text.trim().is_empty() {
+        return Ok(None);
+    }
+
+    return NaiveDate::parse_from_str(text.trim(), "%Y-%m-%d").map(Some).map_err(|_| format!("\"{}\" is not a date like 2024-05-31", text.trim()));
+}
@@ -0,0 +1,158 @@
+use std::path::Path;
+
+use crate::app::{GraphType, Profile, intensity_to_segment};
+use crate::graph::{self, escape_xml};
+use crate::highlights::{DimensionHighlights, HIGHLIGHT_HEADINGS};
+use crate::reflection::Reflection;
+
+const STYLE: &str = "
+body { font-family: sans-serif; color: #333; max-width: 60em; margin: 2em auto; padding: 0 1em; line-height: 1.4; }
+h1 { margin-bottom: 0; }
+h2 { color: #3a8fb7; border-bottom: 2px solid #89cff0; padding-bottom: 0.2em; margin-top: 2em; }
+h3 { margin-bottom: 0.2em; }
+h4 { color: #3a8fb7; font-size: 0.9em; margin: 0.5em 0; }
+table.numbers { border-collapse: collapse; width: 100%; }
+table.numbers th, table.numbers td { border: 1px solid #ddd; padding: 0.4em; text-align: center; }
+table.numbers tr:nth-child(even) { background: #f4f9fc; }
+.graph { text-align: center; }
+.emphasis { font-style: italic; }
+.columns { display: grid; grid-template-columns: repeat(4, 1fr); gap: 1em; font-size: 0.9em; }
+.columns ul { padding-left: 1.2em; margin: 0; }
+dl { display: grid; grid-template-columns: max-content 1fr; gap: 0.3em 1em; }
+dt { font-weight: bold; color: #3a8fb7; }
+dd { margin: 0; }
+.done { text-decoration: line-through; color: #888; }
+@media print { h2 { break-after: avoid; } section { break-inside: avoid; } }
+";
+
+/// Everything that goes into a single-file HTML report of one scored answer sheet.
+pub(crate) struct Report<'a> {
+    pub(crate) name: &'a str,
+    pub(crate) focus: &'a str,
+    pub(crate) tally: (i8, i8, i8, i8),
+    pub(crate) intensity: (i8, i8, i8, i8),
+    pub(crate) graphType: GraphType,
+    pub(crate) profile: &'a Profile,
+    pub(crate) highlights: &'a [DimensionHighlights],
+    pub(crate) reflection: &'a Reflection
+}
+
+impl Report<'_> {
+
+    /// The report as one HTML page with inline CSS and SVG, so it needs no other files.
+    pub(crate) fn to_html(&self) -> String {
+
+        let mut html = format!("<!DOCTYPE html>\n<html lang=\"en\">\n<head>\n<meta charset=\"utf-8\">\n<title>DiSC Report - {name}</title>\n<style>{style}</style>\n</head>\n<body>\n<h1>DiSC Report</h1>\n<p>{name}</p>\n", name = escape_xml(self.name), style = STYLE);
+
+        if !self.focus.is_empty() {
+            html += &format!("<p>Focus: {}</p>\n", escape_xml(self.focus));
+        }
+
+        let tally = [self.tally.0, self.tally.1, self.tally.2, self.tally.3];
+        let intensity = [self.intensity.0, self.intensity.1, self.intensity.2, self.intensity.3];
+
+        html += "<h2>Intensity</h2>\n<table class=\"numbers\">\n<tr><th></th><th>D</th><th>i</th><th>S</th><th>C</th></tr>\n";
+        html += &table_row("Tally", tally.iter().map(|t| t.to_string()));
+        html += &table_row("Intensity", intensity.iter().map(|v| v.to_string()));
+        html += &table_row("Segment", intensity.iter().map(|v| intensity_to_segment(*v).to_string()));
+        html += "</table>\n";
+
+        html += "<h2>Graph</h2>\n<div class=\"graph\">\n";
+        html += &graph::to_svg(&graph::layout(self.graphType, self.intensity));
+        html += "</div>\n";
+
+        html += "<h2>Highlights</h2>\n";
+
+        for (h, value) in self.highlights.iter().zip(intensity) {
+
+            html += &format!("<section>\n<h3>{}</h3>\n<p class=\"emphasis\">{}</p>\n", escape_xml(&h.title), escape_xml(&h.emphasis));
+
+            let segment = intensity_to_segment(value);
+
+            if let Some(n) = h.narrative(segment) {
+                html += &format!("<p><strong>{} (segment {}):</strong> {}</p>\n", escape_xml(&n.label), segment, escape_xml(&n.text));
+            }
+
+            html += "<div class=\"columns\">\n";
+
+            for (heading, list) in HIGHLIGHT_HEADINGS.iter().zip(h.lists()) {
+                html += &format!("<div><h4>{}</h4>\n{}</div>\n", heading, bullet_list(list));
+            }
+
+            html += "</div>\n</section>\n";
+        }
+
+        html += &format!("<h2>Profile Pattern</h2>\n<section>\n<h3>{} Pattern</h3>\n<dl>\n", escape_xml(&self.profile.name));
+
+        for (label, value) in &self.profile.aspects {
+            html += &format!("<dt>{}</dt><dd>{}</dd>\n", escape_xml(label), escape_xml(value));
+        }
+
+        html += "</dl>\n";
+
+        if !self.profile.content.is_empty() {
+            html += &format!("<p>{}</p>\n", escape_xml(&self.profile.content));
+        }
+
+        html += "</section>\n";
+
+        if !self.reflection.is_empty() {
+            html += &self.reflection_html();
+        }
+
+        html += "</body>\n</html>\n";
+        return html;
+    }
+
+    fn reflection_html(&self) -> String {
+
+        let r = self.reflection;
+        let mut html = "<h2>Reflections</h2>\n".to_owned();
+
+        if !r.notes.trim().is_empty() {
+            html += &r.notes.trim().split("\n\n").map(|p| format!("<p>{}</p>\n", escape_xml(p.trim()))).collect::<String>();
+        }
+
+        if !r.resonant.is_empty() {
+            html += "<h3>What Rings True</h3>\n<dl>\n";
+
+            for label in &r.resonant {
+                let value = self.profile.aspects.iter().find(|a| a.0 == *label).map(|a| a.1.to_owned()).unwrap_or_default();
+                html += &format!("<dt>{}</dt><dd>{}</dd>\n", escape_xml(label), escape_xml(&value));
+            }
+
+            html += "</dl>\n";
+        }
+
+        if !r.actions.is_empty() {
+            html += "<h3>Action Plan</h3>\n<table class=\"numbers\">\n<tr><th>Goal</th><th>Due</th><th>Done</th></tr>\n";
+
+            for a in &r.actions {
+                html += &format!("<tr{}><td>{}</td><td>{}</td><td>{}</td></tr>\n",
+                                 if a.done { " class=\"done\"" } else { "" },
+                                 escape_xml(&a.text),
+                                 a.due.map(|d| d.to_string()).unwrap_or_default(),
+                                 if a.done { "✓" } else { "" });
+            }
+
+            html += "</table>\n";
+        }
+
+        return html;
+    }
+
+    pub(crate) fn write(&self, path: &Path) -> Result<(), String> {
+
+        return std::fs::write(path, self.to_html()).map_err(|e| format!("{}: {}", path.display(), e));
+    }
+}
+
+fn table_row(heading: &str, cells: impl Iterator<Item = String>) -> String {
+
+    return format!("<tr><th>{}</th>{}</tr>\n", heading, cells.map(|c| format!("<td>{}</td>", c)).collect::<String>());
+}
+
+fn bullet_list(items: &[String]) -> String {
+
+    return format!("<ul>\n{}</ul>\n", items.iter().map(|i| format!("<li>{}</li>\n", escape_xml(i))).collect::<String>());
+}
@@ -0,0 +1,377 @@
+//! A small local HTTP API over the same scoring code the window uses.
+//!
+//! | Method | Path            | Body / Response                                                        |
+//! |--------|-----------------|------------------------------------------------------------------------|
+//! | GET    | `/instrument`   | the groups of words with their MOST and LEAST scales                   |
+//! | POST   | `/score`        | `{"name": .., "responses": [{"most": .., "least": ..}, ..]}` → result  |
+//! | POST   | `/results`      | as `/score`, but the result is also saved and given an `id`            |
+//! | GET    | `/results`      | the ids of every saved result                                          |
+//! | GET    | `/results/<id>` | one saved result                                                       |
+//! | POST   | `/teams`        | opens a team session and returns its `code` and facilitator `secret`   |
+//! | POST   | `/teams/<code>/join`   | `{"name": ..}` marks a participant as started                   |
+//! | POST   | `/teams/<code>/submit` | as `/results`, also recorded against the participant's name     |
+//! | GET    | `/teams/<code>` | every participant with their result once submitted                     |
+//!
+//! `/instrument` spells scales as instrument files do: D, i, S and C, E for non-scoring words and "-" for none.
+//!
+//! Submissions may say which `form` they answered; only the Standard form is scored here, so any other is refused.
+//!
+//! Participants only ever hear the join code, which lets them join and submit. Reading a team's board needs
+//! `Authorization: Bearer <secret>` with the secret returned when the session was opened.
+//!
+//! The `/results` routes hold people's names, so they need an `Authorization: Bearer <token>` header. Browsers
+//! only get cross-origin access when one origin is allowed, for the web build served from another port.
+//! Request bodies over `MAX_BODY` bytes are refused with 413. Team sessions only live in memory.
+
+use std::collections::HashMap;
+use std::io::Read;
+use std::path::{Path, PathBuf};
+
+use serde_json::{json, Value};
+use tiny_http::{Header, Method, Request, Response, Server};
+
+use crate::answers::find_answer;
+use crate::instrument::{STANDARD_FORM, scale_name};
+use crate::app::{default_questions, default_profiles, process, intensity_to_segment, profilePatternLookup};
+
+/// Largest request body accepted, in bytes; a full answer sheet is well under 4 KiB.
+const MAX_BODY: usize = 64 * 1024;
+
+#[derive(serde::Deserialize)]
+struct Submission {
+    name: Option<String>,
+    /// The instrument form answered; only the Standard form can be scored here.
+    form: Option<String>,
+    responses: Vec<Answer>
+}
+
+#[derive(serde::Deserialize)]
+struct Answer {
+    most: String,
+    least: String
+}
+
+#[derive(serde::Deserialize)]
+struct Join {
+    name: String
+}
+
+/// A team session: the facilitator's secret and everyone who has joined.
+struct Team {
+    secret: String,
+    participants: Vec<Participant>
+}
+
+#[derive(serde::Serialize)]
+struct Participant {
+    name: String,
+    complete: bool,
+    result: Option<Value>
+}
+
+/// Serves requests on `address` until the process is stopped, keeping saved results as JSON files in `store`.
+/// `/results` requests must carry `token`; `origin`, when set, is the one web origin allowed to call the server.
+pub fn serve(address: &str, store: &Path, origin: Option<&str>, token: &str) -> Result<(), String> {
+
+    std::fs::create_dir_all(store).map_err(|e| format!("{}: {}", store.display(), e))?;
+
+    let server = Server::http(address).map_err(|e| format!("{}: {}", address, e))?;
+    println!("listening on http://{}", address);
+
+    let mut teams: HashMap<String, Team> = HashMap::new();
+
+    for mut request in server.incoming_requests() {
+
+        // Browsers ask before sending anything cross-origin.
+        if *request.method() == Method::Options {
+
+            let mut response = Response::empty(204);
+
+            if let Some(origin) = origin {
+                response = response
+                    .with_header(header("Access-Control-Allow-Origin", origin))
+                    .with_header(header("Access-Control-Allow-Methods", "GET, POST"))
+                    .with_header(header("Access-Control-Allow-Headers", "Content-Type, Authorization"));
+            }
+
+            if let Err(e) = request.respond(response) {
+                eprintln!("error: {}", e);
+            }
+            continue;
+        }
+
+        let (status, body) = match handle(&mut request, store, token, &mut teams) {
+            Ok(body) => (200, body),
+            Err((status, message)) => (status, json!({ "error": message }))
+        };
+
+        let mut response = Response::from_string(body.to_string())
+            .with_status_code(status)
+            .with_header(header("Content-Type", "application/json"));
+
+        if let Some(origin) = origin {
+            response = response.with_header(header("Access-Control-Allow-Origin", origin));
+        }
+
+        if let Err(e) = request.respond(response) {
+            eprintln!("error: {}", e);
+        }
+    }
+
+    return Ok(());
+}
+
+fn handle(request: &mut Request, store: &Path, token: &str, teams: &mut HashMap<String, Team>) -> Result<Value, (u16, String)> {
+
+    let path = request.url().split('?').next().unwrap_or("").trim_end_matches('/').to_owned();
+
+    if (path == "/results" || path.starts_with("/results/")) && !has_bearer(request, token) {
+        return Err((401, "a valid bearer token is required".to_owned()));
+    }
+
+    match (request.method(), path.as_str()) {
+        (Method::Get, "/instrument") => Ok(instrument()),
+        (Method::Post, "/score") => score(&read_submission(request)?),
+        (Method::Post, "/results") => {
+
+            let mut result = score(&read_submission(request)?)?;
+            save_result(store, &mut result)?;
+
+            Ok(result)
+        },
+        (Method::Get, "/results") => list_results(store).map_err(|e| (500, e)),
+        (Method::Get, p) if p.starts_with("/results/") => {
+
+            let id = &p["/results/".len()..];
+
+            // Ids are only ever hex, which also keeps requests inside the store.
+            if id.is_empty() || !id.chars().all(|c| c.is_ascii_hexdigit()) {
+                return Err((404, format!("no result \"{}\"", id)));
+            }
+
+            let text = std::fs::read_to_string(result_path(store, id)).map_err(|_| (404, format!("no result \"{}\"", id)))?;
+            serde_json::from_str(&text).map_err(|e| (500, e.to_string()))
+        },
+        (Method::Post, "/teams") => {
+
+            let mut code = new_code().map_err(|e| (500, e))?;
+            while teams.contains_key(&code) { code = new_code().map_err(|e| (500, e))?; }
+
+            let secret = new_token().map_err(|e| (500, e))?;
+
+            teams.insert(code.to_owned(), Team { secret: secret.to_owned(), participants: vec![] });
+            Ok(json!({ "code": code, "secret": secret }))
+        },
+        (method, p) if p.starts_with("/teams/") => {
+
+            let mut parts = p["/teams/".len()..].splitn(2, '/');
+            let code = parts.next().unwrap_or("").to_ascii_uppercase();
+            let action = parts.next().unwrap_or("");
+
+            if !teams.contains_key(&code) {
+                return Err((404, format!("no team session \"{}\"", code)));
+            }
+
+            match (method, action) {
+                (Method::Get, "") => {
+
+                    if !has_bearer(request, &teams[&code].secret) {
+                        return Err((401, "only the facilitator can see the board".to_owned()));
+                    }
+
+                    Ok(json!({ "code": code, "participants": teams[&code].participants }))
+                },
+                (Method::Post, "join") => {
+
+                    let join: Join = read_json(request)?;
+                    let name = join.name.trim().to_owned();
+
+                    if name.is_empty() {
+                        return Err((400, "a name is required".to_owned()));
+                    }
+
+                    let participants = &mut teams.get_mut(&code).unwrap().participants;
+
+                    if !participants.iter().any(|p| p.name == name) {
+                        participants.push(Participant { name: name.to_owned(), complete: false, result: None });
+                    }
+
+                    Ok(json!({ "code": code, "name": name }))
+                },
+                (Method::Post, "submit") => {
+
+                    let submission = read_submission(request)?;
+                    let name = submission.name.to_owned().unwrap_or_default().trim().to_owned();
+
+                    if name.is_empty() {
+                        return Err((400, "a name is required".to_owned()));
+                    }
+
+                    let mut result = score(&submission)?;
+                    save_result(store, &mut result)?;
+
+                    let participants = &mut teams.get_mut(&code).unwrap().participants;
+
+                    match participants.iter_mut().find(|p| p.name == name) {
+                        Some(p) => { p.complete = true; p.result = Some(result.clone()); },
+                        None => participants.push(Participant { name, complete: true, result: Some(result.clone()) })
+                    }
+
+                    Ok(result)
+                },
+                _ => Err((404, format!("no route for {} {}", request.method(), path)))
+            }
+        },
+        _ => Err((404, format!("no route for {} {}", request.method(), path)))
+    }
+}
+
+fn instrument() -> Value {
+
+    let groups: Vec<Value> = default_questions().iter()
+        .map(|group| Value::Array(group.iter().map(|q| json!({ "word": q.0, "most": scale_name(q.1), "least": scale_name(q.2) })).collect()))
+        .collect();
+
+    return json!({ "groups": groups });
+}
+
+fn read_submission(request: &mut Request) -> Result<Submission, (u16, String)> {
+
+    return read_json(request);
+}
+
+fn read_json<T: serde::de::DeserializeOwned>(request: &mut Request) -> Result<T, (u16, String)> {
+
+    if request.body_length().map_or(false, |n| n > MAX_BODY) {
+        return Err((413, format!("request bodies are limited to {} bytes", MAX_BODY)));
+    }
+
+    // Chunked bodies don't say how long they are, so stop reading just past the limit.
+    let mut body = String::new();
+    request.as_reader().take(MAX_BODY as u64 + 1).read_to_string(&mut body).map_err(|e| (400, e.to_string()))?;
+
+    if body.len() > MAX_BODY {
+        return Err((413, format!("request bodies are limited to {} bytes", MAX_BODY)));
+    }
+
+    return serde_json::from_str(&body).map_err(|e| (400, e.to_string()));
+}
+
+/// Scores a submission exactly as the Results page does.
+fn score(submission: &Submission) -> Result<Value, (u16, String)> {
+
+    let questions = default_questions();
+
+    if let Some(form) = submission.form.as_deref().filter(|f| *f != STANDARD_FORM) {
+        return Err((400, format!("only the {} form can be scored, not {}", STANDARD_FORM, form)));
+    }
+
+    if submission.responses.len() != questions.len() {
+        return Err((400, format!("expected {} answered groups but found {}", questions.len(), submission.responses.len())));
+    }
+
+    let mut responses = vec![];
+
+    for (group, answer) in submission.responses.iter().enumerate() {
+        responses.push(find_answer(&questions, group, answer.most.trim(), answer.least.trim()).map_err(|e| (400, format!("group {}: {}", group + 1, e)))?);
+    }
+
+    let mut tally = (0, 0, 0, 0);
+    let mut intensity = (1, 1, 1, 1);
+    process(&mut responses, &mut tally, &mut intensity);
+
+    let seg = (intensity_to_segment(intensity.0), intensity_to_segment(intensity.1), intensity_to_segment(intensity.2), intensity_to_segment(intensity.3));
+    let pattern = profilePatternLookup(&mut default_profiles(), seg);
+
+    return Ok(json!({
+        "name": submission.name,
+        "responses": submission.responses.iter().map(|a| json!({ "most": a.most.trim(), "least": a.least.trim() })).collect::<Vec<Value>>(),
+        "tally": dimensions(tally),
+        "intensity": dimensions(intensity),
+        "segments": dimensions(seg),
+        "pattern": {
+            "name": pattern.name,
+            "aspects": pattern.aspects.iter().map(|a| json!({ "label": a.0, "value": a.1 })).collect::<Vec<Value>>(),
+            "content": pattern.content
+        }
+    }));
+}
+
+fn list_results(store: &Path) -> Result<Value, String> {
+
+    let mut ids = vec![];
+
+    for entry in std::fs::read_dir(store).map_err(|e| e.to_string())? {
+
+        let path = entry.map_err(|e| e.to_string())?.path();
+
+        if path.extension().map_or(false, |e| e == "json") {
+            if let Some(stem) = path.file_stem() { ids.push(stem.to_string_lossy().into_owned()); }
+        }
+    }
+
+    ids.sort();
+    return Ok(json!({ "ids": ids }));
+}
+
+/// Gives a result a new id and writes it to the store.
+fn save_result(store: &Path, result: &mut Value) -> Result<(), (u16, String)> {
+
+    let id = new_id().map_err(|e| (500, e))?;
+    result["id"] = json!(id);
+
+    return std::fs::write(result_path(store, &id), result.to_string()).map_err(|e| (500, e.to_string()));
+}
+
+fn result_path(store: &Path, id: &str) -> PathBuf {
+
+    return store.join(format!("{}.json", id));
+}
+
+fn new_id() -> Result<String, String> {
+
+    let mut bytes = [0u8; 8];
+    getrandom::getrandom(&mut bytes).map_err(|e| e.to_string())?;
+
+    return Ok(bytes.iter().map(|b| format!("{:02x}", b)).collect());
+}
+
+/// Six characters that are easy to read aloud to a room.
+fn new_code() -> Result<String, String> {
+
+    const ALPHABET: &[u8] = b"ABCDEFGHJKLMNPQRSTUVWXYZ23456789";
+
+    let mut bytes = [0u8; 6];
+    getrandom::getrandom(&mut bytes).map_err(|e| e.to_string())?;
+
+    return Ok(bytes.iter().map(|b| ALPHABET[*b as usize % ALPHABET.len()] as char).collect());
+}
+
+/// Whether the request carries `Authorization: Bearer <token>`, compared without an early exit.
+fn has_bearer(request: &Request, token: &str) -> bool {
+
+    let expected = format!("Bearer {}", token);
+
+    return request.headers().iter()
+        .filter(|h| h.field.equiv("Authorization"))
+        .any(|h| h.value.as_str().len() == expected.len() && h.value.as_str().bytes().zip(expected.bytes()).fold(0, |acc, (a, b)| acc | (a ^ b)) == 0);
+}
+
+/// A random token for the `/results` routes, for when none is given on the command line.
+pub fn new_token() -> Result<String, String> {
+
+    let mut bytes = [0u8; 16];
+    getrandom::getrandom(&mut bytes).map_err(|e| e.to_string())?;
+
+    return Ok(bytes.iter().map(|b| format!("{:02x}", b)).collect());
+}
+
+fn header(name: &str, value: &str) -> Header {
+
+    return Header::from_bytes(name.as_bytes(), value.as_bytes()).unwrap();
+}
+
+fn dimensions(val: (i8, i8, i8, i8)) -> Value {
+
+    return json!({ "D": val.0, "i": val.1, "S": val.2, "C": val.3 });
+}
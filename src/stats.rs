@@ -0,0 +1,162 @@
+/// Saved results needed before the Results page ranks someone against them.
+pub(crate) const NORM_MIN_RESULTS: usize = 5;
+
+/// Summary of one dimension across a norm group.
+#[derive(PartialEq, Clone, Copy, Debug)]
+pub(crate) struct Describe {
+    pub(crate) count: usize,
+    pub(crate) mean: f64,
+    /// Sample standard deviation; zero for a single value.
+    pub(crate) sd: f64,
+    pub(crate) min: f64,
+    pub(crate) median: f64,
+    pub(crate) max: f64
+}
+
+/// Tally and intensity summaries for D, i, S and C, and how often each pattern occurs.
+pub(crate) struct Norms {
+    pub(crate) tally: [Describe; 4],
+    pub(crate) intensity: [Describe; 4],
+    /// (pattern name, count), most frequent first.
+    pub(crate) patterns: Vec<(String, usize)>
+}
+
+pub(crate) fn describe(values: &[f64]) -> Option<Describe> {
+
+    if values.is_empty() {
+        return None;
+    }
+
+    let n = values.len() as f64;
+    let mean = values.iter().sum::<f64>() / n;
+    let variance = if values.len() > 1 { values.iter().map(|v| (v - mean).powi(2)).sum::<f64>() / (n - 1.0) } else { 0.0 };
+
+    return Some(Describe {
+        count: values.len(),
+        mean,
+        sd: variance.sqrt(),
+        min: percentile(values, 0.0),
+        median: percentile(values, 50.0),
+        max: percentile(values, 100.0)
+    });
+}
+
+/// The value below which `p` percent of `values` fall, interpolating between neighbours.
+pub(crate) fn percentile(values: &[f64], p: f64) -> f64 {
+
+    let mut sorted = values.to_vec();
+    sorted.sort_by(|a, b| a.partial_cmp(b).unwrap_or(std::cmp::Ordering::Equal));
+
+    if sorted.is_empty() {
+        return f64::NAN;
+    }
+
+    let position = (p.clamp(0.0, 100.0) / 100.0) * (sorted.len() - 1) as f64;
+    let (lower, upper) = (position.floor() as usize, position.ceil() as usize);
+
+    return sorted[lower] + (sorted[upper] - sorted[lower]) * (position - lower as f64);
+}
+
+/// Percent of `values` below `x`, counting ties as half, so the middle of the group ranks 50.
+pub(crate) fn percentile_rank(values: &[f64], x: f64) -> f64 {
+
+    if values.is_empty() {
+        return f64::NAN;
+    }
+
+    let below = values.iter().filter(|v| **v < x).count() as f64;
+    let equal = values.iter().filter(|v| **v == x).count() as f64;
+
+    return 100.0 * (below + equal / 2.0) / values.len() as f64;
+}
+
+/// Norms over (tally, intensity, pattern name) for each result in the group.
+pub(crate) fn norms(results: &[((i8, i8, i8, i8), (i8, i8, i8, i8), String)]) -> Option<Norms> {
+
+    if results.is_empty() {
+        return None;
+    }
+
+    let column = |d: usize, pick: fn(&((i8, i8, i8, i8), (i8, i8, i8, i8), String)) -> (i8, i8, i8, i8)| -> Vec<f64> {
+        results.iter().map(|r| { let v = pick(r); [v.0, v.1, v.2, v.3][d] as f64 }).collect()
+    };
+
+    let tally = [0, 1, 2, 3].map(|d| describe(&column(d, |r| r.0)).unwrap());
+    let intensity = [0, 1, 2, 3].map(|d| describe(&column(d, |r| r.1)).unwrap());
+
+    let mut patterns: Vec<(String, usize)> = vec![];
+
+    for r in results {
+        match patterns.iter_mut().find(|p| p.0 == r.2) {
+            Some(p) => p.1 += 1,
+            None => patterns.push((r.2.to_owned(), 1))
+        }
+    }
+
+    patterns.sort_by(|a, b| b.1.cmp(&a.1).then(a.0.cmp(&b.0)));
+
+    return Some(Norms { tally, intensity, patterns });
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    #[test]
+    fn percentile_interpolates_between_neighbours() {
+
+        let values = [4.0, 1.0, 3.0, 2.0];
+
+        assert_eq!(percentile(&values, 0.0), 1.0);
+        assert_eq!(percentile(&values, 25.0), 1.75);
+        assert_eq!(percentile(&values, 50.0), 2.5);
+        assert_eq!(percentile(&values, 100.0), 4.0);
+        assert_eq!(percentile(&[7.0], 50.0), 7.0);
+        assert!(percentile(&[], 50.0).is_nan());
+    }
+
+    #[test]
+    fn percentile_rank_counts_ties_as_half() {
+
+        let values = [1.0, 2.0, 2.0, 3.0];
+
+        assert_eq!(percentile_rank(&values, 0.0), 0.0);
+        assert_eq!(percentile_rank(&values, 1.0), 12.5);
+        assert_eq!(percentile_rank(&values, 2.0), 50.0);
+        assert_eq!(percentile_rank(&values, 3.0), 87.5);
+        assert_eq!(percentile_rank(&values, 4.0), 100.0);
+        assert_eq!(percentile_rank(&[5.0, 5.0, 5.0], 5.0), 50.0);
+        assert!(percentile_rank(&[], 1.0).is_nan());
+    }
+
+    #[test]
+    fn describe_uses_the_sample_deviation() {
+
+        let d = describe(&[2.0, 4.0, 4.0, 4.0, 5.0, 5.0, 7.0, 9.0]).unwrap();
+
+        assert_eq!((d.count, d.mean, d.min, d.median, d.max), (8, 5.0, 2.0, 4.5, 9.0));
+        assert!((d.sd - (32.0_f64 / 7.0).sqrt()).abs() < 1e-12);
+
+        assert_eq!(describe(&[3.0]).unwrap().sd, 0.0);
+        assert!(describe(&[]).is_none());
+    }
+
+    #[test]
+    fn norms_rank_patterns_by_frequency_then_name() {
+
+        let results = vec![
+            ((1, 2, 3, 4), (10, 12, 14, 16), "Promoter".to_owned()),
+            ((0, 0, 0, 0), (14, 14, 14, 14), "Agent".to_owned()),
+            ((2, 2, 2, 2), (18, 18, 18, 18), "Promoter".to_owned()),
+            ((4, 3, 2, 1), (20, 16, 12, 8), "Creative".to_owned())
+        ];
+
+        let n = norms(&results).unwrap();
+
+        assert_eq!(n.patterns, vec![("Promoter".to_owned(), 2), ("Agent".to_owned(), 1), ("Creative".to_owned(), 1)]);
+        assert_eq!(n.intensity[0].mean, 15.5);
+        assert_eq!(n.tally[3].median, 1.5);
+        assert!(norms(&[]).is_none());
+    }
+}
@@ -0,0 +1,76 @@
+use argon2::Argon2;
+use chacha20poly1305::{XChaCha20Poly1305, XNonce, Key, KeyInit, aead::Aead};
+use zeroize::Zeroizing;
+
+/// Data encrypted with a key derived from a passphrase, in the form it is persisted.
+#[derive(serde::Deserialize, serde::Serialize, PartialEq, Clone, Debug, Default)]
+pub(crate) struct Sealed {
+    salt: Vec<u8>,
+    nonce: Vec<u8>,
+    ciphertext: Vec<u8>
+}
+
+/// A key derived from the storage passphrase. It only ever lives in memory and is wiped on drop.
+pub(crate) struct VaultKey {
+    key: Zeroizing<[u8; 32]>,
+    salt: Vec<u8>
+}
+
+impl VaultKey {
+
+    /// Derives a key for a new store under a fresh random salt.
+    pub(crate) fn create(passphrase: &str) -> Result<Self, String> {
+
+        if passphrase.chars().count() < 8 {
+            return Err("the passphrase must be at least 8 characters".to_owned());
+        }
+
+        let mut salt = vec![0u8; 16];
+        getrandom::getrandom(&mut salt).map_err(|e| e.to_string())?;
+
+        return derive(passphrase, salt);
+    }
+
+    /// Derives the key for existing data and checks it by decrypting.
+    pub(crate) fn unlock<T: serde::de::DeserializeOwned>(sealed: &Sealed, passphrase: &str) -> Result<(Self, T), String> {
+
+        let key = derive(passphrase, sealed.salt.clone())?;
+        let value = key.open(sealed)?;
+
+        return Ok((key, value));
+    }
+
+    pub(crate) fn seal<T: serde::Serialize>(&self, value: &T) -> Result<Sealed, String> {
+
+        let plaintext = Zeroizing::new(serde_json::to_vec(value).map_err(|e| e.to_string())?);
+
+        let mut nonce = vec![0u8; 24];
+        getrandom::getrandom(&mut nonce).map_err(|e| e.to_string())?;
+
+        let cipher = XChaCha20Poly1305::new(Key::from_slice(&self.key[..]));
+        let ciphertext = cipher.encrypt(XNonce::from_slice(&nonce), plaintext.as_slice()).map_err(|_| "encryption failed".to_owned())?;
+
+        return Ok(Sealed { salt: self.salt.clone(), nonce, ciphertext });
+    }
+
+    pub(crate) fn open<T: serde::de::DeserializeOwned>(&self, sealed: &Sealed) -> Result<T, String> {
+
+        if sealed.nonce.len() != 24 {
+            return Err("the encrypted data is damaged".to_owned());
+        }
+
+        let cipher = XChaCha20Poly1305::new(Key::from_slice(&self.key[..]));
+        let plaintext = Zeroizing::new(cipher.decrypt(XNonce::from_slice(&sealed.nonce), sealed.ciphertext.as_slice()).map_err(|_| "incorrect passphrase".to_owned())?);
+
+        return serde_json::from_slice(&plaintext).map_err(|e| e.to_string());
+    }
+}
+
+fn derive(passphrase: &str, salt: Vec<u8>) -> Result<VaultKey, String> {
+
+    let mut key = Zeroizing::new([0u8; 32]);
+    Argon2::default().hash_password_into(passphrase.as_bytes(), &salt, &mut key[..]).map_err(|e| e.to_string())?;
+
+    return Ok(VaultKey { key, salt });
+}
+